@@ -80,5 +80,16 @@ mod test {
 
         assert_float_array_eq!(2e-15f64, find_roots_biquadratic(1f64, 0f64, -1f64), [-1f64, 1f64]);
         assert_float_array_eq!(2e-15f64, find_roots_biquadratic(1f64, -5f64, 4f64), [-2f64, -1f64, 1f64, 2f64]);
+
+        // Coefficients built from roots
+        let p = coefficients_from_roots(&[
+            (Complex::from(-3f64), 1),
+            (Complex::from(-0.5f64), 1),
+            (Complex::from(0.5f64), 1),
+            (Complex::from(3f64), 1),
+        ]);
+        assert_float_array_eq!(2e-15f64, find_roots_biquadratic(p[0], p[2], p[4]), [-3f64, -0.5f64, 0.5f64, 3f64]);
+        let p = coefficients_from_roots(&[(Complex::from(-2f64), 1), (Complex::from(2f64), 1), (Complex::new(0f64, 1f64), 1)]);
+        assert_float_array_eq!(2e-15f64, find_roots_biquadratic(p[0], p[2], p[4]), [-2f64, 2f64]);
     }
 }
@@ -66,5 +66,13 @@ mod test {
     fn test_find_roots_cubic() {
         assert_eq!(find_roots_cubic(1f32, 0f32, 0f32, 0f32).next(), Some(0f32));
         assert_float_array_eq!(1e-15, find_roots_cubic(1f64, 0f64, -1f64, 0f64), [-1f64, 0f64, 1f64]);
+
+        // Coefficients built from roots
+        let p = coefficients_from_roots(&[(Complex::from(-3f64), 1), (Complex::from(0.5f64), 1), (Complex::from(2f64), 1)]);
+        assert_float_array_eq!(2e-15, find_roots_cubic(p[0], p[1], p[2], p[3]), [-3f64, 0.5f64, 2f64]);
+        let p = coefficients_from_roots(&[(Complex::from(-1f64), 1), (Complex::new(2f64, 1f64), 1)]);
+        assert_float_array_eq!(1e-15, find_roots_cubic(p[0], p[1], p[2], p[3]), [-1f64]);
+        let p = coefficients_from_roots(&[(Complex::from(1.5f64), 3)]);
+        assert_float_array_eq!(1e-15, find_roots_cubic(p[0], p[1], p[2], p[3]), [1.5f64]);
     }
 }
@@ -86,5 +86,11 @@ mod test {
         assert_float_array_eq!(1e-15, find_roots_cubic_depressed(-2f64, 2f64), [-1.769292354238631415240409f64]);
         assert_float_array_eq!(1e-15, find_roots_cubic_depressed(-3f64, 2f64), [-2f64, 1f64]);
         assert_float_array_eq!(1e-15, find_roots_cubic_depressed(-2f64, 1f64), [(-1f64 - 5f64.sqrt()) / 2f64, (-1f64 + 5f64.sqrt()) / 2f64, 1f64]);
+
+        // Coefficients built from roots
+        let p = coefficients_from_roots(&[(Complex::from(-3f64), 1), (Complex::from(1f64), 1), (Complex::from(2f64), 1)]);
+        assert_float_array_eq!(2e-15, find_roots_cubic_depressed(p[2], p[3]), [-3f64, 1f64, 2f64]);
+        let p = coefficients_from_roots(&[(Complex::from(2f64), 1), (Complex::new(-1f64, 3f64), 1)]);
+        assert_float_array_eq!(1e-15, find_roots_cubic_depressed(p[2], p[3]), [2f64]);
     }
 }
@@ -95,5 +95,11 @@ mod test {
                 2.813606502648330815441228f64
             ]
         );
+
+        // Coefficients built from roots
+        let p = coefficients_from_roots(&[(Complex::from(-3f64), 1), (Complex::from(0.5f64), 1), (Complex::from(2f64), 1)]);
+        assert_float_array_eq!(2e-15, find_roots_cubic_normalized(p[1], p[2], p[3]), [-3f64, 0.5f64, 2f64]);
+        let p = coefficients_from_roots(&[(Complex::from(4f64), 1), (Complex::new(-1f64, 3f64), 1)]);
+        assert_float_array_eq!(1e-13, find_roots_cubic_normalized(p[1], p[2], p[3]), [4f64]);
     }
 }
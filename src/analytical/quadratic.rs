@@ -103,6 +103,19 @@ mod test {
         assert_eq!(find_roots_quadratic(0f32, 0f32, 0f32).next(), Some(0f32));
         assert_eq!(find_roots_quadratic(1f32, 0f32, 1f32).next(), None);
         assert_float_array_eq!(1e-15, find_roots_quadratic(1f64, 0f64, -1f64), [-1f64, 1f64]);
+
+        // Coefficients built from roots
+        let p = coefficients_from_roots(&[(Complex::from(-3f64), 1), (Complex::from(0.5f64), 1)]);
+        assert_float_array_eq!(1e-15, find_roots_quadratic(p[0], p[1], p[2]), [-3f64, 0.5f64]);
+        let p = coefficients_from_roots(&[(Complex::from(7f64), 2)]);
+        assert_float_array_eq!(1e-15, find_roots_quadratic(p[0], p[1], p[2]), [7f64]);
+        let p = coefficients_from_roots(&[(Complex::new(1f64, 2f64), 1)]);
+        assert_eq!(find_roots_quadratic(p[0], p[1], p[2]).next(), None);
+
+        // Roots of very different magnitudes keep a small backward error
+        let p = coefficients_from_roots(&[(Complex::from(1e-8f64), 1), (Complex::from(1e8f64), 1)]);
+        assert_float_array_eq!(1e-15, find_roots_quadratic(p[0], p[1], p[2]), [1e-8f64, 1e8f64]);
+        assert!(check_roots(&p, find_roots_quadratic(p[0], p[1], p[2])).max_backward_error < 1e-15f64);
     }
 
     #[test]
@@ -94,5 +94,18 @@ mod test {
         assert_float_array_eq!(2e-15f64, find_roots_quartic(3f64, 5f64, -5f64, -5f64, 2f64), [-2f64, -1f64, 0.33333333333333333f64, 1f64]);
 
         assert_float_array_eq!(5e-7, find_roots_quartic(3f32, 5f32, -5f32, -5f32, 2f32), [-2f32, -1f32, 0.33333333333333333f32, 1f32]);
+
+        // Coefficients built from roots
+        let p = coefficients_from_roots(&[
+            (Complex::from(-3f64), 1),
+            (Complex::from(0.5f64), 1),
+            (Complex::from(2f64), 1),
+            (Complex::from(5f64), 1),
+        ]);
+        assert_float_array_eq!(2e-15f64, find_roots_quartic(p[0], p[1], p[2], p[3], p[4]), [-3f64, 0.5f64, 2f64, 5f64]);
+        let p = coefficients_from_roots(&[(Complex::from(-1f64), 1), (Complex::from(3f64), 1), (Complex::new(1f64, 1f64), 1)]);
+        assert_float_array_eq!(2e-15f64, find_roots_quartic(p[0], p[1], p[2], p[3], p[4]), [-1f64, 3f64]);
+        let p = coefficients_from_roots(&[(Complex::new(2f64, 1f64), 1), (Complex::new(-1f64, 0.5f64), 1)]);
+        assert_eq!(find_roots_quartic(p[0], p[1], p[2], p[3], p[4]).next(), None);
     }
 }
@@ -105,5 +105,16 @@ mod test {
             0.67861075799846644f64,
             2.84999984604581877f64
         ]);
+
+        // Coefficients built from roots
+        let p = coefficients_from_roots(&[
+            (Complex::from(-3f64), 1),
+            (Complex::from(-1f64), 1),
+            (Complex::from(0.5f64), 1),
+            (Complex::from(3.5f64), 1),
+        ]);
+        assert_float_array_eq!(1e-15, find_roots_quartic_depressed(p[2], p[3], p[4]), [-3f64, -1f64, 0.5f64, 3.5f64]);
+        let p = coefficients_from_roots(&[(Complex::from(-1f64), 1), (Complex::from(3f64), 1), (Complex::new(-1f64, 1f64), 1)]);
+        assert_float_array_eq!(1e-15, find_roots_quartic_depressed(p[2], p[3], p[4]), [-1f64, 3f64]);
     }
 }
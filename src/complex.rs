@@ -0,0 +1,181 @@
+// Copyright (c) 2015, Mikhail Vorotilov
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without
+// modification, are permitted provided that the following conditions are met:
+//
+// * Redistributions of source code must retain the above copyright notice, this
+//   list of conditions and the following disclaimer.
+//
+// * Redistributions in binary form must reproduce the above copyright notice,
+//   this list of conditions and the following disclaimer in the documentation
+//   and/or other materials provided with the distribution.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+// AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+// IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+// FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+// DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+// CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+// OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+// OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use super::FloatType;
+
+use core::ops::Add;
+use core::ops::Div;
+use core::ops::Mul;
+use core::ops::Neg;
+use core::ops::Sub;
+
+/// Complex number re + i*im, used for complex roots of polynomials.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Complex<F: FloatType> {
+    pub re: F,
+    pub im: F,
+}
+
+impl<F: FloatType> Complex<F> {
+    pub fn new(re: F, im: F) -> Self {
+        Self { re, im }
+    }
+
+    pub fn zero() -> Self {
+        Self::new(F::zero(), F::zero())
+    }
+
+    pub fn one() -> Self {
+        Self::new(F::one(), F::zero())
+    }
+
+    pub fn conj(self) -> Self {
+        Self::new(self.re, -self.im)
+    }
+
+    /// Squared modulus re^2 + im^2
+    pub fn norm_sqr(self) -> F {
+        self.re * self.re + self.im * self.im
+    }
+
+    /// Modulus, computed without an intermediate overflow
+    pub fn abs(self) -> F {
+        let (a, b) = (self.re.abs(), self.im.abs());
+        let (big, small) = if a > b { (a, b) } else { (b, a) };
+        if big == F::zero() {
+            F::zero()
+        } else {
+            let ratio = small / big;
+            big * (F::one() + ratio * ratio).sqrt()
+        }
+    }
+
+    /// Principal square root
+    pub fn sqrt(self) -> Self {
+        if self.re == F::zero() && self.im == F::zero() {
+            return Self::zero();
+        }
+        // sqrt(z) = sqrt((|z| + re)/2) + i*sign(im)*sqrt((|z| - re)/2), computed without cancellation
+        let t = ((self.abs() + self.re.abs()) / F::two()).sqrt();
+        if self.re >= F::zero() {
+            Self::new(t, self.im / (F::two() * t))
+        } else if self.im >= F::zero() {
+            Self::new(self.im.abs() / (F::two() * t), t)
+        } else {
+            Self::new(self.im.abs() / (F::two() * t), -t)
+        }
+    }
+}
+
+impl<F: FloatType> From<F> for Complex<F> {
+    fn from(re: F) -> Self {
+        Self::new(re, F::zero())
+    }
+}
+
+impl<F: FloatType> Add for Complex<F> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.re + other.re, self.im + other.im)
+    }
+}
+
+impl<F: FloatType> Sub for Complex<F> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.re - other.re, self.im - other.im)
+    }
+}
+
+impl<F: FloatType> Neg for Complex<F> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.re, -self.im)
+    }
+}
+
+impl<F: FloatType> Mul for Complex<F> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Self::new(
+            self.re * other.re - self.im * other.im,
+            self.re * other.im + self.im * other.re,
+        )
+    }
+}
+
+impl<F: FloatType> Mul<F> for Complex<F> {
+    type Output = Self;
+
+    fn mul(self, factor: F) -> Self {
+        Self::new(self.re * factor, self.im * factor)
+    }
+}
+
+impl<F: FloatType> Div for Complex<F> {
+    type Output = Self;
+
+    /// Smith's algorithm avoids overflow of the intermediate results
+    fn div(self, other: Self) -> Self {
+        if other.re.abs() >= other.im.abs() {
+            let ratio = other.im / other.re;
+            let denominator = other.re + other.im * ratio;
+            Self::new(
+                (self.re + self.im * ratio) / denominator,
+                (self.im - self.re * ratio) / denominator,
+            )
+        } else {
+            let ratio = other.re / other.im;
+            let denominator = other.re * ratio + other.im;
+            Self::new(
+                (self.re * ratio + self.im) / denominator,
+                (self.im * ratio - self.re) / denominator,
+            )
+        }
+    }
+}
+
+impl<F: FloatType> Div<F> for Complex<F> {
+    type Output = Self;
+
+    fn div(self, divisor: F) -> Self {
+        Self::new(self.re / divisor, self.im / divisor)
+    }
+}
+
+#[test]
+fn test_complex() {
+    let a = Complex::new(1f64, 2f64);
+    let b = Complex::new(3f64, -4f64);
+    assert_eq!(a * b, Complex::new(11f64, 2f64));
+    assert_eq!((a * b) / b, a);
+    assert_eq!(b.abs(), 5f64);
+    assert_eq!(Complex::new(-4f64, 0f64).sqrt(), Complex::new(0f64, 2f64));
+    assert_eq!(Complex::new(3f64, -4f64).sqrt(), Complex::new(2f64, -1f64));
+    assert_eq!(Complex::new(-3f64, -4f64).sqrt(), Complex::new(1f64, -2f64));
+}
@@ -0,0 +1,207 @@
+// Copyright (c) 2015, Mikhail Vorotilov
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without
+// modification, are permitted provided that the following conditions are met:
+//
+// * Redistributions of source code must retain the above copyright notice, this
+//   list of conditions and the following disclaimer.
+//
+// * Redistributions in binary form must reproduce the above copyright notice,
+//   this list of conditions and the following disclaimer in the documentation
+//   and/or other materials provided with the distribution.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+// AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+// IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+// FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+// DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+// CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+// OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+// OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use super::FloatType;
+use super::Step;
+
+/// Conditions to stop an iterative search.
+///
+/// Solvers pass magnitudes: the absolute value (or the norm) of the current
+/// residual, and the size of the last step. Implement this trait to customize
+/// the tolerance and the number of allowed iterations.
+///
+/// Solvers of equations in one variable also report every step and the result
+/// of the search; these callbacks do nothing by default (see Stats).
+pub trait Convergency<F: FloatType> {
+    /// Returns true if the residual is small enough to accept the current estimate
+    fn is_root_found(&mut self, y: F) -> bool;
+    /// Returns true if the last step is small enough to stop iterating
+    fn is_converged(&mut self, dx: F) -> bool;
+    /// Returns true if the solver must stop after the given number of iterations
+    fn is_iteration_limit_reached(&mut self, iter: usize) -> bool;
+    /// Called after every iteration, before the conditions above are checked
+    fn on_iteration(&mut self, _step: &Step<F>) {}
+    /// Called when the search ends with the root found, or with None on failure
+    fn on_result(&mut self, _root: Option<F>) {}
+}
+
+impl Convergency<f32> for f32 {
+    fn is_root_found(&mut self, y: f32) -> bool {
+        y.abs() < self.abs()
+    }
+    fn is_converged(&mut self, dx: f32) -> bool {
+        dx.abs() < self.abs()
+    }
+    fn is_iteration_limit_reached(&mut self, iter: usize) -> bool {
+        iter >= 30
+    }
+}
+
+impl Convergency<f64> for f64 {
+    fn is_root_found(&mut self, y: f64) -> bool {
+        y.abs() < self.abs()
+    }
+    fn is_converged(&mut self, dx: f64) -> bool {
+        dx.abs() < self.abs()
+    }
+    fn is_iteration_limit_reached(&mut self, iter: usize) -> bool {
+        iter >= 30
+    }
+}
+
+/// Convergency with a configurable tolerance and iteration limit.
+///
+/// # Examples
+///
+/// ```
+/// use roots::Convergency;
+/// use roots::SimpleConvergency;
+///
+/// let mut convergency = SimpleConvergency { eps: 1e-10f64, max_iter: 100 };
+/// assert!(convergency.is_root_found(1e-11f64));
+/// assert!(!convergency.is_iteration_limit_reached(99));
+/// assert!(convergency.is_iteration_limit_reached(100));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SimpleConvergency<F: FloatType> {
+    /// Tolerance for both the residual and the step size
+    pub eps: F,
+    /// Maximal number of iterations
+    pub max_iter: usize,
+}
+
+impl<F: FloatType> Convergency<F> for SimpleConvergency<F> {
+    fn is_root_found(&mut self, y: F) -> bool {
+        y.abs() < self.eps.abs()
+    }
+    fn is_converged(&mut self, dx: F) -> bool {
+        dx.abs() < self.eps.abs()
+    }
+    fn is_iteration_limit_reached(&mut self, iter: usize) -> bool {
+        iter >= self.max_iter
+    }
+}
+
+/// Convergency which collects statistics of a search, leaving decisions to another convergency.
+///
+/// Pass it to a solver instead of the convergency, and read the statistics next to the returned root.
+///
+/// # Examples
+///
+/// ```
+/// use roots::find_root_brent;
+/// use roots::Stats;
+///
+/// let mut stats = Stats::new(1e-15f64);
+/// let root = find_root_brent(0f64, 1f64, |x: f64| x.cos() - x, &mut stats);
+/// assert_eq!(stats.root, root.ok());
+/// assert!(stats.iterations > 0 && stats.evaluations > stats.iterations);
+/// assert!(stats.residual.unwrap() < 1e-15f64);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats<F: FloatType, C> {
+    /// The convergency deciding when to stop
+    pub convergency: C,
+    /// Number of iterations
+    pub iterations: usize,
+    /// Number of evaluations of the function
+    pub evaluations: usize,
+    /// Number of evaluations of the derivative
+    pub derivative_evaluations: usize,
+    /// |f| at the last estimate
+    pub residual: Option<F>,
+    /// Width of the last bracket around the root, for bracketing methods
+    pub bracket_width: Option<F>,
+    /// The root found; None if the search has failed or has not ended yet
+    pub root: Option<F>,
+}
+
+impl<F: FloatType, C: Convergency<F>> Stats<F, C> {
+    /// Empty statistics
+    pub fn new(convergency: C) -> Self {
+        Stats {
+            convergency,
+            iterations: 0,
+            evaluations: 0,
+            derivative_evaluations: 0,
+            residual: None,
+            bracket_width: None,
+            root: None,
+        }
+    }
+}
+
+impl<F: FloatType, C: Convergency<F>> Convergency<F> for Stats<F, C> {
+    fn is_root_found(&mut self, y: F) -> bool {
+        self.convergency.is_root_found(y)
+    }
+    fn is_converged(&mut self, dx: F) -> bool {
+        self.convergency.is_converged(dx)
+    }
+    fn is_iteration_limit_reached(&mut self, iter: usize) -> bool {
+        self.convergency.is_iteration_limit_reached(iter)
+    }
+    fn on_iteration(&mut self, step: &Step<F>) {
+        self.iterations += 1;
+        self.evaluations += step.evaluations;
+        self.derivative_evaluations += step.derivative_evaluations;
+        self.residual = Some(step.y.abs());
+        self.bracket_width = step.bracket.map(|(a, b)| b - a);
+        self.convergency.on_iteration(step);
+    }
+    fn on_result(&mut self, root: Option<F>) {
+        self.root = root;
+        self.convergency.on_result(root);
+    }
+}
+
+#[test]
+fn test_float_convergency() {
+    assert!(1e-10f64.is_root_found(-1e-11f64));
+    assert!(!1e-10f64.is_converged(1e-9f64));
+    assert!(1e-5f32.is_iteration_limit_reached(30));
+}
+
+#[test]
+fn test_stats() {
+    use super::*;
+
+    let mut stats = Stats::new(SimpleConvergency { eps: 1e-15f64, max_iter: 100 });
+    let root = find_root_newton_bracketed(0f64, 2f64, |x: f64| (x * x - 2f64, 2f64 * x), &mut stats).unwrap();
+    assert_eq!(stats.root, Some(root));
+    assert_eq!(stats.evaluations, stats.derivative_evaluations);
+    // 3 evaluations at the start and one for each iteration
+    assert_eq!(stats.evaluations, stats.iterations + 3);
+    assert!(stats.bracket_width.is_some() && stats.residual.unwrap() < 1e-15f64);
+
+    // The bracket search and the solver are both counted
+    let mut stats = Stats::new(1e-15f64);
+    find_root_regula_falsi_from(0f64, |x: f64| x - 100f64, &mut stats).unwrap();
+    // 8 steps of the expansion from [0, 0.1] and one step of the regula falsi
+    assert_eq!((stats.iterations, stats.evaluations, stats.derivative_evaluations), (9, 13, 0));
+
+    let mut stats = Stats::new(SimpleConvergency { eps: 0f64, max_iter: 3 });
+    assert!(find_root_brent(0f64, 1f64, |x: f64| x.cos() - x, &mut stats).is_err());
+    assert_eq!((stats.iterations, stats.root), (3, None));
+}
@@ -0,0 +1,76 @@
+// Copyright (c) 2015, Mikhail Vorotilov
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without
+// modification, are permitted provided that the following conditions are met:
+//
+// * Redistributions of source code must retain the above copyright notice, this
+//   list of conditions and the following disclaimer.
+//
+// * Redistributions in binary form must reproduce the above copyright notice,
+//   this list of conditions and the following disclaimer in the documentation
+//   and/or other materials provided with the distribution.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+// AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+// IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+// FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+// DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+// CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+// OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+// OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use core::convert::Infallible;
+use std::error::Error;
+use std::fmt;
+
+/// Possible errors of the iterative solvers.
+///
+/// E is the type of errors of the function being solved, see find_root_brent_fallible.
+/// Solvers of infallible functions use the default Infallible.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchError<E = Infallible> {
+    /// The algorithm could not converge within the given number of iterations
+    NoConvergency,
+    /// The derivative (or the Jacobian matrix) is zero or singular
+    ZeroDerivative,
+    /// Exact integer arithmetic has overflowed
+    Overflow,
+    /// A rational number has a zero denominator
+    ZeroDenominator,
+    /// The function has the same sign at both ends of the initial interval
+    NoBracketing,
+    /// The function being solved has returned an error
+    Function(E),
+}
+
+impl SearchError {
+    /// Converts an error of a search over an infallible function into the error of any search
+    pub(crate) fn lift<E>(self) -> SearchError<E> {
+        match self {
+            SearchError::NoConvergency => SearchError::NoConvergency,
+            SearchError::ZeroDerivative => SearchError::ZeroDerivative,
+            SearchError::Overflow => SearchError::Overflow,
+            SearchError::ZeroDenominator => SearchError::ZeroDenominator,
+            SearchError::NoBracketing => SearchError::NoBracketing,
+            SearchError::Function(never) => match never {},
+        }
+    }
+}
+
+impl<E: fmt::Display> fmt::Display for SearchError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SearchError::NoConvergency => write!(f, "Convergency Error"),
+            SearchError::ZeroDerivative => write!(f, "Zero Derivative Error"),
+            SearchError::Overflow => write!(f, "Overflow Error"),
+            SearchError::ZeroDenominator => write!(f, "Zero Denominator Error"),
+            SearchError::NoBracketing => write!(f, "Bracketing Error"),
+            SearchError::Function(error) => write!(f, "Function Error: {}", error),
+        }
+    }
+}
+
+impl<E: fmt::Debug + fmt::Display> Error for SearchError<E> {}
@@ -62,6 +62,8 @@ pub trait FloatType:
         Self::nine() * Self::three()
     }
     fn two_third_pi() -> Self;
+    /// The difference between 1 and the next representable number
+    fn epsilon() -> Self;
     fn sqrt(self) -> Self;
     /// The cubic root function is pow(x, 1/3) accepting negative arguments
     fn cbrt(self) -> Self {
@@ -73,6 +75,9 @@ pub trait FloatType:
     }
     fn acos(self) -> Self;
     fn cos(self) -> Self;
+    fn sin(self) -> Self;
+    /// The natural logarithm
+    fn ln(self) -> Self;
     fn abs(self) -> Self;
     fn powf(self, n: Self) -> Self;
 }
@@ -110,6 +115,10 @@ impl FloatType for f32 {
     fn pi() -> Self {
         core::f32::consts::PI
     }
+
+    fn epsilon() -> Self {
+        f32::EPSILON
+    }
     fn sqrt(self) -> Self {
         self.sqrt()
     }
@@ -119,6 +128,12 @@ impl FloatType for f32 {
     fn cos(self) -> Self {
         self.cos()
     }
+    fn sin(self) -> Self {
+        self.sin()
+    }
+    fn ln(self) -> Self {
+        self.ln()
+    }
     fn abs(self) -> Self {
         self.abs()
     }
@@ -160,6 +175,10 @@ impl FloatType for f64 {
     fn pi() -> Self {
         core::f64::consts::PI
     }
+
+    fn epsilon() -> Self {
+        f64::EPSILON
+    }
     fn sqrt(self) -> Self {
         self.sqrt()
     }
@@ -169,6 +188,12 @@ impl FloatType for f64 {
     fn cos(self) -> Self {
         self.cos()
     }
+    fn sin(self) -> Self {
+        self.sin()
+    }
+    fn ln(self) -> Self {
+        self.ln()
+    }
     fn abs(self) -> Self {
         self.abs()
     }
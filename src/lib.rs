@@ -31,6 +31,8 @@
 //! This crate contains various algorithms for numerical and analytical solving
 //! of 1-variable equations like f(x)=0. Only real roots are calculated.
 //! Multiple (double etc.) roots are considered as one root.
+//! Small systems of nonlinear equations F(x)=0 in several variables are
+//! solved by the functions in the multivariate module.
 //!
 //! # Use
 //!
@@ -72,8 +74,19 @@ macro_rules! assert_float_array_eq(
 );
 
 pub mod analytical;
+pub mod complex;
+pub mod convergency;
+pub mod error;
 pub mod float;
+pub mod multivariate;
+pub mod numerical;
+pub mod polynomial;
 
+pub use self::complex::Complex;
+pub use self::convergency::Convergency;
+pub use self::convergency::SimpleConvergency;
+pub use self::convergency::Stats;
+pub use self::error::SearchError;
 pub use self::float::FloatType;
 
 pub use self::analytical::biquadratic::find_roots_biquadratic;
@@ -85,3 +98,79 @@ pub use self::analytical::quadratic::find_roots_quadratic;
 pub use self::analytical::quartic::find_roots_quartic;
 pub use self::analytical::quartic_depressed::find_roots_quartic_depressed;
 pub use self::analytical::roots::Roots;
+pub use self::multivariate::broyden::find_root_broyden_bad;
+pub use self::multivariate::broyden::find_root_broyden_good;
+pub use self::multivariate::lu::solve_linear_system;
+pub use self::multivariate::newton::find_root_newton_system;
+pub use self::numerical::bracket::find_bracket;
+pub use self::numerical::bracket::find_bracket_in_grid;
+pub use self::numerical::bracket::BracketError;
+pub use self::numerical::bracket::BracketExpansion;
+pub use self::numerical::brent::find_root_brent;
+pub use self::numerical::brent::find_root_brent_fallible;
+pub use self::numerical::brent::find_root_brent_from;
+pub use self::numerical::brent::Brent;
+pub use self::numerical::fixed_point::find_fixed_point;
+pub use self::numerical::fixed_point::find_fixed_point_aitken;
+pub use self::numerical::fixed_point::find_fixed_point_anderson;
+pub use self::numerical::fixed_point::find_fixed_point_steffensen;
+pub use self::numerical::fixed_point::Aitken;
+pub use self::numerical::fixed_point::FixedPointIteration;
+pub use self::numerical::fixed_point::Steffensen;
+pub use self::numerical::newton_bracketed::find_root_newton_bracketed;
+pub use self::numerical::newton_bracketed::find_root_newton_bracketed_fallible;
+pub use self::numerical::newton_bracketed::find_root_newton_bracketed_from;
+pub use self::numerical::newton_bracketed::NewtonBracketed;
+pub use self::numerical::newton_raphson::find_root_newton_raphson;
+pub use self::numerical::newton_raphson::find_root_newton_raphson_fallible;
+pub use self::numerical::newton_raphson::NewtonRaphson;
+pub use self::numerical::regula_falsi::find_root_regula_falsi;
+pub use self::numerical::regula_falsi::find_root_regula_falsi_fallible;
+pub use self::numerical::regula_falsi::find_root_regula_falsi_from;
+pub use self::numerical::regula_falsi::RegulaFalsi;
+pub use self::numerical::secant::find_root_secant;
+pub use self::numerical::secant::find_root_secant_fallible;
+pub use self::numerical::secant::Secant;
+pub use self::numerical::subdivision::find_all_roots_in;
+pub use self::numerical::subdivision::SubdivisionOptions;
+pub use self::numerical::Step;
+pub use self::polynomial::aberth::find_roots_aberth;
+pub use self::polynomial::aberth::ComplexRoot;
+pub use self::polynomial::bernstein::BernsteinPolynomial;
+pub use self::polynomial::bounds::cauchy_bound;
+pub use self::polynomial::bounds::descartes_root_counts;
+pub use self::polynomial::bounds::fujiwara_bound;
+pub use self::polynomial::bounds::kojima_bound;
+pub use self::polynomial::bounds::lagrange_bound;
+pub use self::polynomial::bounds::lower_bound;
+pub use self::polynomial::chebyshev::ChebyshevSeries;
+pub use self::polynomial::dynamic::Polynomial;
+pub use self::polynomial::durand_kerner::find_roots_durand_kerner;
+pub use self::polynomial::eigen::find_roots_eigen;
+pub use self::polynomial::eigen::find_roots_eigen_complex;
+pub use self::polynomial::exact::find_roots_exact;
+pub use self::polynomial::extrema::find_extrema_cubic;
+pub use self::polynomial::extrema::find_extrema_quartic;
+pub use self::polynomial::extrema::find_extrema_quintic;
+pub use self::polynomial::extrema::CriticalPoint;
+pub use self::polynomial::extrema::CriticalPointKind;
+pub use self::polynomial::extrema::Extrema;
+pub use self::polynomial::fixed::FixedPolynomial;
+pub use self::polynomial::inequality::solve_poly_inequality;
+pub use self::polynomial::inequality::Interval;
+pub use self::polynomial::inequality::Relation;
+pub use self::polynomial::intersection::find_intersections;
+pub use self::polynomial::intersection::Intersection;
+pub use self::polynomial::laguerre::eval_with_second_derivative;
+pub use self::polynomial::laguerre::find_roots_laguerre;
+pub use self::polynomial::rpoly::find_roots_rpoly;
+pub use self::polynomial::sturm::find_roots_sturm;
+pub use self::polynomial::sturm::SturmChain;
+pub use self::polynomial::vca::budan_fourier_bound;
+pub use self::polynomial::vca::find_roots_vca;
+pub use self::polynomial::vca::isolate_roots_vca;
+pub use self::polynomial::vca::sign_variations;
+pub use self::polynomial::check_roots;
+pub use self::polynomial::coefficients_from_roots;
+pub use self::polynomial::RootsCheck;
+pub use self::polynomial::RootsVec;
@@ -0,0 +1,270 @@
+// Copyright (c) 2015, Mikhail Vorotilov
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without
+// modification, are permitted provided that the following conditions are met:
+//
+// * Redistributions of source code must retain the above copyright notice, this
+//   list of conditions and the following disclaimer.
+//
+// * Redistributions in binary form must reproduce the above copyright notice,
+//   this list of conditions and the following disclaimer in the documentation
+//   and/or other materials provided with the distribution.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+// AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+// IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+// FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+// DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+// CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+// OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+// OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use super::super::numerical::dot;
+use super::super::numerical::norm;
+use super::super::Convergency;
+use super::super::FloatType;
+use super::super::SearchError;
+use super::lu::solve_linear_system;
+
+/// Approximates the Jacobian matrix of f at x by forward differences
+fn finite_difference_jacobian<F, Func, const N: usize>(f: &Func, x: &[F; N], fx: &[F; N]) -> [[F; N]; N]
+where
+    F: FloatType,
+    Func: Fn(&[F; N]) -> [F; N],
+{
+    let sqrt_epsilon = F::epsilon().sqrt();
+    let mut jacobian = [[F::zero(); N]; N];
+    for j in 0..N {
+        let h = if x[j].abs() > F::one() { sqrt_epsilon * x[j].abs() } else { sqrt_epsilon };
+        let mut x_h = *x;
+        x_h[j] = x[j] + h;
+        // Use the representable step to reduce the rounding error
+        let h = x_h[j] - x[j];
+        let fx_h = f(&x_h);
+        for (row, (&fx_h_i, &fx_i)) in jacobian.iter_mut().zip(fx_h.iter().zip(fx.iter())) {
+            row[j] = (fx_h_i - fx_i) / h;
+        }
+    }
+    jacobian
+}
+
+fn mat_vec<F: FloatType, const N: usize>(m: &[[F; N]; N], v: &[F; N]) -> [F; N] {
+    let mut result = [F::zero(); N];
+    for (r, row) in result.iter_mut().zip(m.iter()) {
+        *r = dot(row, v);
+    }
+    result
+}
+
+/// Find a root of a system of N equations F(x)=0 in N variables by the "good" Broyden method.
+///
+/// The initial Jacobian matrix is approximated by finite differences,
+/// then it is corrected by a rank-one update after each step.
+/// No derivatives are required, and all calculations are done on the stack.
+/// The Convergency receives the norm of F(x) and the norm of the last step.
+///
+/// # Failures
+/// ## ZeroDerivative
+/// The approximated Jacobian matrix is singular, or the step is too small to update it.
+/// ## NoConvergency
+/// The algorithm cannot find a root within the given number of iterations.
+///
+/// # Examples
+///
+/// ```
+/// use roots::find_root_broyden_good;
+///
+/// // x^2 + y^2 = 4; x*y = 1
+/// let f = |v: &[f64; 2]| [v[0] * v[0] + v[1] * v[1] - 4f64, v[0] * v[1] - 1f64];
+///
+/// let root = find_root_broyden_good([2f64, 0.5f64], f, &mut 1e-14f64);
+/// // Returns approximately Ok([1.9318516525781366, 0.5176380902050415])
+/// ```
+pub fn find_root_broyden_good<F, Func, Conv, const N: usize>(start: [F; N], f: Func, convergency: &mut Conv) -> Result<[F; N], SearchError>
+where
+    F: FloatType,
+    Func: Fn(&[F; N]) -> [F; N],
+    Conv: Convergency<F>,
+{
+    let mut x = start;
+    let mut fx = f(&x);
+    let mut jacobian = finite_difference_jacobian(&f, &x, &fx);
+    let mut iter = 0;
+    loop {
+        if convergency.is_root_found(norm(&fx)) {
+            return Ok(x);
+        }
+
+        let mut minus_fx = fx;
+        for v in minus_fx.iter_mut() {
+            *v = -*v;
+        }
+        let dx = match solve_linear_system(jacobian, minus_fx) {
+            Some(dx) => dx,
+            None => return Err(SearchError::ZeroDerivative),
+        };
+
+        let mut x_new = x;
+        for (x_i, &dx_i) in x_new.iter_mut().zip(dx.iter()) {
+            *x_i = *x_i + dx_i;
+        }
+        let fx_new = f(&x_new);
+
+        let dx_norm = norm(&dx);
+        if convergency.is_converged(dx_norm) {
+            return Ok(x_new);
+        }
+
+        // J += (dF - J*dx) * dx^T / (dx^T * dx)
+        let dx_dx = dx_norm * dx_norm;
+        if dx_dx == F::zero() {
+            return Err(SearchError::ZeroDerivative);
+        }
+        let j_dx = mat_vec(&jacobian, &dx);
+        for (row, ((&fx_new_i, &fx_i), &j_dx_i)) in jacobian.iter_mut().zip(fx_new.iter().zip(fx.iter()).zip(j_dx.iter())) {
+            let u = (fx_new_i - fx_i - j_dx_i) / dx_dx;
+            for (j, &dx_j) in row.iter_mut().zip(dx.iter()) {
+                *j = *j + u * dx_j;
+            }
+        }
+
+        x = x_new;
+        fx = fx_new;
+
+        iter += 1;
+        if convergency.is_iteration_limit_reached(iter) {
+            return Err(SearchError::NoConvergency);
+        }
+    }
+}
+
+/// Find a root of a system of N equations F(x)=0 in N variables by the "bad" Broyden method.
+///
+/// The inverse of the Jacobian matrix is approximated by finite differences at the start point,
+/// then it is corrected by a rank-one update after each step, so no linear system is solved
+/// during the iterations. All calculations are done on the stack.
+/// The Convergency receives the norm of F(x) and the norm of the last step.
+///
+/// # Failures
+/// ## ZeroDerivative
+/// The approximated Jacobian matrix is singular, or the residual stops changing.
+/// ## NoConvergency
+/// The algorithm cannot find a root within the given number of iterations.
+///
+/// # Examples
+///
+/// ```
+/// use roots::find_root_broyden_bad;
+///
+/// // x^2 + y^2 = 4; x*y = 1
+/// let f = |v: &[f64; 2]| [v[0] * v[0] + v[1] * v[1] - 4f64, v[0] * v[1] - 1f64];
+///
+/// let root = find_root_broyden_bad([2f64, 0.5f64], f, &mut 1e-14f64);
+/// // Returns approximately Ok([1.9318516525781366, 0.5176380902050415])
+/// ```
+pub fn find_root_broyden_bad<F, Func, Conv, const N: usize>(start: [F; N], f: Func, convergency: &mut Conv) -> Result<[F; N], SearchError>
+where
+    F: FloatType,
+    Func: Fn(&[F; N]) -> [F; N],
+    Conv: Convergency<F>,
+{
+    let mut x = start;
+    let mut fx = f(&x);
+
+    // Invert the initial Jacobian column by column
+    let jacobian = finite_difference_jacobian(&f, &x, &fx);
+    let mut inverse = [[F::zero(); N]; N];
+    for j in 0..N {
+        let mut e = [F::zero(); N];
+        e[j] = F::one();
+        let column = match solve_linear_system(jacobian, e) {
+            Some(column) => column,
+            None => return Err(SearchError::ZeroDerivative),
+        };
+        for (row, &c) in inverse.iter_mut().zip(column.iter()) {
+            row[j] = c;
+        }
+    }
+
+    let mut iter = 0;
+    loop {
+        if convergency.is_root_found(norm(&fx)) {
+            return Ok(x);
+        }
+
+        let mut dx = mat_vec(&inverse, &fx);
+        for v in dx.iter_mut() {
+            *v = -*v;
+        }
+
+        let mut x_new = x;
+        for (x_i, &dx_i) in x_new.iter_mut().zip(dx.iter()) {
+            *x_i = *x_i + dx_i;
+        }
+        let fx_new = f(&x_new);
+
+        if convergency.is_converged(norm(&dx)) {
+            return Ok(x_new);
+        }
+
+        // H += (dx - H*dF) * dF^T / (dF^T * dF)
+        let mut df = fx_new;
+        for (df_i, &fx_i) in df.iter_mut().zip(fx.iter()) {
+            *df_i = *df_i - fx_i;
+        }
+        let df_df = dot(&df, &df);
+        if df_df == F::zero() {
+            return Err(SearchError::ZeroDerivative);
+        }
+        let h_df = mat_vec(&inverse, &df);
+        for (row, (&dx_i, &h_df_i)) in inverse.iter_mut().zip(dx.iter().zip(h_df.iter())) {
+            let u = (dx_i - h_df_i) / df_df;
+            for (h, &df_j) in row.iter_mut().zip(df.iter()) {
+                *h = *h + u * df_j;
+            }
+        }
+
+        x = x_new;
+        fx = fx_new;
+
+        iter += 1;
+        if convergency.is_iteration_limit_reached(iter) {
+            return Err(SearchError::NoConvergency);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::super::*;
+
+    #[test]
+    fn test_find_root_broyden_good() {
+        let f = |v: &[f64; 2]| [v[0] * v[0] + v[1] * v[1] - 4f64, v[0] * v[1] - 1f64];
+        let root = find_root_broyden_good([2f64, 0.5f64], f, &mut 1e-14f64).unwrap();
+        assert_float_array_eq!(1e-13, root.iter().cloned(), [1.9318516525781366f64, 0.5176380902050415f64]);
+
+        // The Jacobian of a constant function is zero
+        assert_eq!(find_root_broyden_good([1f64, 1f64], |_: &[f64; 2]| [1f64, 1f64], &mut 1e-14f64), Err(SearchError::ZeroDerivative));
+
+        // The square of the step underflows, so the Jacobian cannot be updated
+        let f = |v: &[f64; 2]| [2f64 * v[0] - 1e-200f64, v[0] + v[1] - 1e-200f64];
+        let mut convergency = SimpleConvergency { eps: 0f64, max_iter: 10 };
+        assert_eq!(find_root_broyden_good([0f64, 0f64], f, &mut convergency), Err(SearchError::ZeroDerivative));
+    }
+
+    #[test]
+    fn test_find_root_broyden_bad() {
+        let f = |v: &[f64; 2]| [v[0] * v[0] + v[1] * v[1] - 4f64, v[0] * v[1] - 1f64];
+        let root = find_root_broyden_bad([2f64, 0.5f64], f, &mut 1e-14f64).unwrap();
+        assert_float_array_eq!(1e-13, root.iter().cloned(), [1.9318516525781366f64, 0.5176380902050415f64]);
+
+        let f3 = |v: &[f32; 3]| [v[0] + v[1] + v[2] - 6f32, v[0] * v[1] - 2f32, v[2] - 3f32];
+        let mut convergency = SimpleConvergency { eps: 1e-6f32, max_iter: 50 };
+        let root = find_root_broyden_bad([0.5f32, 2.5f32, 3.5f32], f3, &mut convergency).unwrap();
+        assert_float_array_eq!(1e-5, root.iter().cloned(), [1f32, 2f32, 3f32]);
+    }
+}
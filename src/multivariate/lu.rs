@@ -0,0 +1,105 @@
+// Copyright (c) 2015, Mikhail Vorotilov
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without
+// modification, are permitted provided that the following conditions are met:
+//
+// * Redistributions of source code must retain the above copyright notice, this
+//   list of conditions and the following disclaimer.
+//
+// * Redistributions in binary form must reproduce the above copyright notice,
+//   this list of conditions and the following disclaimer in the documentation
+//   and/or other materials provided with the distribution.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+// AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+// IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+// FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+// DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+// CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+// OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+// OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use super::super::FloatType;
+
+/// Solves a dense linear system a*x = b using the LU decomposition with partial pivoting.
+///
+/// Returns None if the matrix is singular to the working precision.
+///
+/// # Examples
+///
+/// ```
+/// use roots::solve_linear_system;
+///
+/// // 2*x + y = 3; x + 3*y = 5
+/// let x = solve_linear_system([[2f64, 1f64], [1f64, 3f64]], [3f64, 5f64]).unwrap();
+/// // Returns [0.8, 1.4]
+///
+/// let singular = solve_linear_system([[1f64, 2f64], [2f64, 4f64]], [1f64, 1f64]);
+/// assert_eq!(singular, None);
+/// ```
+pub fn solve_linear_system<F: FloatType, const N: usize>(mut a: [[F; N]; N], mut b: [F; N]) -> Option<[F; N]> {
+    // Singularity threshold relative to the largest element of the matrix
+    let scale = a
+        .iter()
+        .flat_map(|row| row.iter())
+        .fold(F::zero(), |acc, &x| if x.abs() > acc { x.abs() } else { acc });
+    let threshold = scale * F::epsilon() * F::from(N as i16);
+
+    for k in 0..N {
+        // Find the pivot
+        let mut pivot = k;
+        for i in k + 1..N {
+            if a[i][k].abs() > a[pivot][k].abs() {
+                pivot = i;
+            }
+        }
+        if a[pivot][k].abs() <= threshold {
+            return None;
+        }
+        a.swap(k, pivot);
+        b.swap(k, pivot);
+
+        // Eliminate the column below the pivot
+        let (upper, lower) = a.split_at_mut(k + 1);
+        let pivot_row = &upper[k];
+        let b_k = b[k];
+        for (row, b_i) in lower.iter_mut().zip(b[k + 1..].iter_mut()) {
+            let factor = row[k] / pivot_row[k];
+            for (x, &p) in row[k..].iter_mut().zip(pivot_row[k..].iter()) {
+                *x = *x - factor * p;
+            }
+            *b_i = *b_i - factor * b_k;
+        }
+    }
+
+    // Back substitution
+    let mut x = [F::zero(); N];
+    for i in (0..N).rev() {
+        let sum = (i + 1..N).fold(b[i], |acc, j| acc - a[i][j] * x[j]);
+        x[i] = sum / a[i][i];
+    }
+    Some(x)
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::super::*;
+
+    #[test]
+    fn test_solve_linear_system() {
+        assert_float_array_eq!(1e-15, solve_linear_system([[2f64, 1f64], [1f64, 3f64]], [3f64, 5f64]).unwrap().iter().cloned(), [0.8f64, 1.4f64]);
+        // Requires pivoting: the first diagonal element is zero
+        assert_float_array_eq!(
+            1e-15,
+            solve_linear_system([[0f64, 1f64, 1f64], [1f64, 0f64, 1f64], [1f64, 1f64, 0f64]], [2f64, 2f64, 2f64])
+                .unwrap()
+                .iter()
+                .cloned(),
+            [1f64, 1f64, 1f64]
+        );
+        assert_eq!(solve_linear_system([[1f32, 2f32], [2f32, 4f32]], [1f32, 1f32]), None);
+    }
+}
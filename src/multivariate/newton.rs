@@ -0,0 +1,156 @@
+// Copyright (c) 2015, Mikhail Vorotilov
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without
+// modification, are permitted provided that the following conditions are met:
+//
+// * Redistributions of source code must retain the above copyright notice, this
+//   list of conditions and the following disclaimer.
+//
+// * Redistributions in binary form must reproduce the above copyright notice,
+//   this list of conditions and the following disclaimer in the documentation
+//   and/or other materials provided with the distribution.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+// AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+// IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+// FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+// DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+// CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+// OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+// OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use super::super::numerical::norm;
+use super::super::Convergency;
+use super::super::FloatType;
+use super::super::SearchError;
+use super::lu::solve_linear_system;
+
+/// Find a root of a system of N equations F(x)=0 in N variables by the Newton method.
+///
+/// The Jacobian matrix J(x)[i][j] = dF_i/dx_j must be supplied by the caller.
+/// Each Newton step is damped by a backtracking line search until the norm of F(x) decreases.
+/// The Convergency receives the norm of F(x) and the norm of the last step.
+///
+/// # Failures
+/// ## ZeroDerivative
+/// The Jacobian matrix is singular at the current estimate.
+/// ## NoConvergency
+/// The algorithm cannot find a root within the given number of iterations.
+///
+/// # Examples
+///
+/// ```
+/// use roots::find_root_newton_system;
+///
+/// // x^2 + y^2 = 4; x*y = 1
+/// let f = |v: &[f64; 2]| [v[0] * v[0] + v[1] * v[1] - 4f64, v[0] * v[1] - 1f64];
+/// let jacobian = |v: &[f64; 2]| [[2f64 * v[0], 2f64 * v[1]], [v[1], v[0]]];
+///
+/// let root = find_root_newton_system([2f64, 0f64], f, jacobian, &mut 1e-14f64);
+/// // Returns approximately Ok([1.9318516525781366, 0.5176380902050415])
+/// ```
+pub fn find_root_newton_system<F, Func, Jac, Conv, const N: usize>(
+    start: [F; N],
+    f: Func,
+    jacobian: Jac,
+    convergency: &mut Conv,
+) -> Result<[F; N], SearchError>
+where
+    F: FloatType,
+    Func: Fn(&[F; N]) -> [F; N],
+    Jac: Fn(&[F; N]) -> [[F; N]; N],
+    Conv: Convergency<F>,
+{
+    // Sufficient decrease of the residual norm required by the line search
+    let alpha = F::one() / F::from(10000i16);
+    let min_lambda = F::one() / F::from(1024i16);
+
+    let mut x = start;
+    let mut fx = f(&x);
+    let mut fx_norm = norm(&fx);
+    let mut iter = 0;
+    loop {
+        if convergency.is_root_found(fx_norm) {
+            return Ok(x);
+        }
+
+        let mut minus_fx = fx;
+        for v in minus_fx.iter_mut() {
+            *v = -*v;
+        }
+        let dx = match solve_linear_system(jacobian(&x), minus_fx) {
+            Some(dx) => dx,
+            None => return Err(SearchError::ZeroDerivative),
+        };
+
+        // Backtracking line search: halve the step until the residual decreases enough
+        let mut lambda = F::one();
+        let (x_new, fx_new, fx_new_norm) = loop {
+            let mut x_new = x;
+            for (x_i, &dx_i) in x_new.iter_mut().zip(dx.iter()) {
+                *x_i = *x_i + lambda * dx_i;
+            }
+            let fx_new = f(&x_new);
+            let fx_new_norm = norm(&fx_new);
+            if fx_new_norm <= (F::one() - alpha * lambda) * fx_norm || lambda <= min_lambda {
+                break (x_new, fx_new, fx_new_norm);
+            }
+            lambda = lambda / F::two();
+        };
+
+        x = x_new;
+        fx = fx_new;
+        fx_norm = fx_new_norm;
+
+        if convergency.is_converged(lambda * norm(&dx)) {
+            return Ok(x);
+        }
+
+        iter += 1;
+        if convergency.is_iteration_limit_reached(iter) {
+            return Err(SearchError::NoConvergency);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::super::*;
+
+    #[test]
+    fn test_find_root_newton_system() {
+        let f = |v: &[f64; 2]| [v[0] * v[0] + v[1] * v[1] - 4f64, v[0] * v[1] - 1f64];
+        let jacobian = |v: &[f64; 2]| [[2f64 * v[0], 2f64 * v[1]], [v[1], v[0]]];
+
+        let root = find_root_newton_system([2f64, 0f64], f, jacobian, &mut 1e-14f64).unwrap();
+        assert_float_array_eq!(1e-14, root.iter().cloned(), [1.9318516525781366f64, 0.5176380902050415f64]);
+
+        // Singular Jacobian at the start point
+        assert_eq!(find_root_newton_system([0f64, 0f64], f, jacobian, &mut 1e-14f64), Err(SearchError::ZeroDerivative));
+    }
+
+    #[test]
+    fn test_find_root_newton_system_damped() {
+        // Undamped Newton overshoots far away from the root when started at x = 3
+        let f = |v: &[f64; 3]| [v[0].atan(), v[1] - v[0], v[2] * v[2] * v[2] - 8f64];
+        let jacobian = |v: &[f64; 3]| {
+            [
+                [1f64 / (1f64 + v[0] * v[0]), 0f64, 0f64],
+                [-1f64, 1f64, 0f64],
+                [0f64, 0f64, 3f64 * v[2] * v[2]],
+            ]
+        };
+
+        let mut convergency = SimpleConvergency { eps: 1e-14f64, max_iter: 100 };
+        let root = find_root_newton_system([3f64, 1f64, 1f64], f, jacobian, &mut convergency).unwrap();
+        assert_float_array_eq!(1e-14, root.iter().cloned(), [0f64, 0f64, 2f64]);
+
+        assert_eq!(
+            find_root_newton_system([3f64, 1f64, 1f64], f, jacobian, &mut SimpleConvergency { eps: 1e-14f64, max_iter: 2 }),
+            Err(SearchError::NoConvergency)
+        );
+    }
+}
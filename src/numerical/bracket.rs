@@ -0,0 +1,278 @@
+// Copyright (c) 2015, Mikhail Vorotilov
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without
+// modification, are permitted provided that the following conditions are met:
+//
+// * Redistributions of source code must retain the above copyright notice, this
+//   list of conditions and the following disclaimer.
+//
+// * Redistributions in binary form must reproduce the above copyright notice,
+//   this list of conditions and the following disclaimer in the documentation
+//   and/or other materials provided with the distribution.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+// AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+// IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+// FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+// DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+// CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+// OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+// OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use super::super::Convergency;
+use super::super::FloatType;
+use super::super::SearchError;
+use super::Step;
+
+use std::error::Error;
+use std::fmt;
+
+/// Default first step of the bracket search around x0
+pub(super) fn initial_step<F: FloatType>(x0: F) -> F {
+    (x0.abs() + F::one()) / F::from(10i16)
+}
+
+/// Error of find_bracket: f has the same sign at both ends of the explored interval
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BracketError<F: FloatType> {
+    /// Ordered ends of the interval explored before the iterations ran out
+    pub explored: (F, F),
+}
+
+impl<F: FloatType, E> From<BracketError<F>> for SearchError<E> {
+    fn from(_: BracketError<F>) -> Self {
+        SearchError::NoBracketing
+    }
+}
+
+impl<F: FloatType> fmt::Display for BracketError<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Bracketing Error in [{:?}, {:?}]", self.explored.0, self.explored.1)
+    }
+}
+
+impl<F: FloatType> Error for BracketError<F> {}
+
+/// Geometric expansion of an interval [a, b] until f changes its sign, as a sequence of steps.
+///
+/// At each step the end with the smaller |f| moves outwards, so the interval grows
+/// by the factor 1.6 (see zbrac in Numerical Recipes). The step reports the moved end
+/// as x, and the explored interval as its bracket. Steps end when a sign change is found.
+///
+/// # Examples
+///
+/// ```
+/// use roots::BracketExpansion;
+///
+/// let mut expansion = BracketExpansion::new(0f64, 1f64, |x: f64| x - 5f64);
+/// let explored: Vec<(f64, f64)> = expansion.by_ref().map(|step| step.bracket.unwrap()).collect();
+/// assert_eq!(explored, vec![(0f64, 2.6f64), (0f64, 6.76f64)]);
+/// assert!(expansion.bracket().is_some());
+/// ```
+#[derive(Debug, Clone)]
+pub struct BracketExpansion<F: FloatType, Func> {
+    f: Func,
+    a: F,
+    b: F,
+    fa: F,
+    fb: F,
+    /// Evaluations not reported by a step yet
+    evaluations: usize,
+}
+
+impl<F, Func> BracketExpansion<F, Func>
+where
+    F: FloatType,
+    Func: FnMut(F) -> F,
+{
+    /// Starts the expansion from [x0, x0 + step]
+    pub fn new(x0: F, step: F, mut f: Func) -> Self {
+        let (a, b) = (x0, x0 + step);
+        let (fa, fb) = (f(a), f(b));
+        BracketExpansion {
+            f,
+            a,
+            b,
+            fa,
+            fb,
+            evaluations: 2,
+        }
+    }
+
+    /// Ordered ends of the explored interval
+    pub fn explored(&self) -> (F, F) {
+        super::ordered(self.a, self.b)
+    }
+
+    /// Ordered ends of the interval, if f changes its sign there; either of them may be a root
+    pub fn bracket(&self) -> Option<(F, F)> {
+        if self.fa == F::zero() || self.fb == F::zero() || (self.fa > F::zero()) != (self.fb > F::zero()) {
+            Some(super::ordered(self.a, self.b))
+        } else {
+            None
+        }
+    }
+}
+
+impl<F, Func> Iterator for BracketExpansion<F, Func>
+where
+    F: FloatType,
+    Func: FnMut(F) -> F,
+{
+    type Item = Step<F>;
+
+    fn next(&mut self) -> Option<Step<F>> {
+        if self.bracket().is_some() {
+            return None;
+        }
+        let factor = F::from(16i16) / F::from(10i16);
+        let (x, y) = if self.fa.abs() < self.fb.abs() {
+            self.a = self.a + factor * (self.a - self.b);
+            self.fa = (self.f)(self.a);
+            (self.a, self.fa)
+        } else {
+            self.b = self.b + factor * (self.b - self.a);
+            self.fb = (self.f)(self.b);
+            (self.b, self.fb)
+        };
+        self.evaluations += 1;
+        Some(Step {
+            x,
+            y,
+            dx: (self.b - self.a).abs(),
+            bracket: Some(super::ordered(self.a, self.b)),
+            evaluations: core::mem::take(&mut self.evaluations),
+            derivative_evaluations: 0,
+        })
+    }
+}
+
+/// Finds an interval [a, b] where f changes its sign, starting from [x0, x0 + step].
+///
+/// The interval is expanded by BracketExpansion. After n iterations the explored
+/// interval is wider than step * 1.6^n. Returned ends are ordered; either of them may be a root.
+/// The Convergency only limits the number of iterations, and receives the steps of the expansion.
+///
+/// # Failures
+/// No sign change is found within the given number of iterations. The error holds the explored interval,
+/// and converts into SearchError::NoBracketing.
+///
+/// # Examples
+///
+/// ```
+/// use roots::find_bracket;
+/// use roots::find_root_brent;
+/// use roots::BracketError;
+/// use roots::SimpleConvergency;
+///
+/// let f = |x: f64| x * x * x - 1000f64;
+/// let (a, b) = find_bracket(&f, 0f64, 1f64, &mut 1e-15f64).unwrap();
+/// assert!(a <= 10f64 && 10f64 <= b);
+/// let root = find_root_brent(a, b, &f, &mut 1e-15f64);
+/// // Returns approximately Ok(10)
+///
+/// // One iteration explores [0, 2.6]
+/// let error = find_bracket(&f, 0f64, 1f64, &mut SimpleConvergency { eps: 1e-15f64, max_iter: 1 });
+/// assert_eq!(error, Err(BracketError { explored: (0f64, 2.6f64) }));
+/// ```
+pub fn find_bracket<F, Func, Conv>(f: Func, x0: F, step: F, convergency: &mut Conv) -> Result<(F, F), BracketError<F>>
+where
+    F: FloatType,
+    Func: FnMut(F) -> F,
+    Conv: Convergency<F>,
+{
+    let mut expansion = BracketExpansion::new(x0, step, f);
+    let mut iter = 0;
+    loop {
+        if let Some(bracket) = expansion.bracket() {
+            return Ok(bracket);
+        }
+        if convergency.is_iteration_limit_reached(iter) {
+            convergency.on_result(None);
+            return Err(BracketError {
+                explored: expansion.explored(),
+            });
+        }
+        if let Some(step) = expansion.next() {
+            convergency.on_iteration(&step);
+        }
+        iter += 1;
+    }
+}
+
+/// Finds the leftmost of the given number of equal segments of [a, b] where f changes its sign.
+///
+/// A segment ending at a root of f is reported too. The number of segments is clamped to 1..=32767.
+///
+/// # Failures
+/// ## NoBracketing
+/// f has the same sign at all ends of segments.
+///
+/// # Examples
+///
+/// ```
+/// use roots::find_bracket_in_grid;
+///
+/// // The ends of [0, 10] have the same sign, so it is not a bracket itself
+/// let f = |x: f64| (x - 2f64) * (x - 3f64);
+/// assert_eq!(find_bracket_in_grid(f, 0f64, 10f64, 20), Ok((1.5f64, 2f64)));
+/// ```
+pub fn find_bracket_in_grid<F, Func>(mut f: Func, a: F, b: F, segments: usize) -> Result<(F, F), SearchError>
+where
+    F: FloatType,
+    Func: FnMut(F) -> F,
+{
+    let segments = segments.clamp(1, i16::MAX as usize) as i16;
+    let n = F::from(segments);
+    let mut left = a;
+    let mut f_left = f(left);
+    for i in 1..=segments {
+        let right = a + (b - a) * F::from(i) / n;
+        let f_right = f(right);
+        if f_left == F::zero() || f_right == F::zero() || (f_left > F::zero()) != (f_right > F::zero()) {
+            return Ok((left, right));
+        }
+        left = right;
+        f_left = f_right;
+    }
+    Err(SearchError::NoBracketing)
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::super::*;
+    use core::convert::Infallible;
+
+    #[test]
+    fn test_find_bracket() {
+        let f = |x: f64| x * x * x - 1000f64;
+        let (a, b) = find_bracket(f, 0f64, 1f64, &mut 1e-15f64).unwrap();
+        assert!(a < b && f(a) < 0f64 && f(b) > 0f64);
+        let (a, b) = find_bracket(f, 100f64, -1f64, &mut 1e-15f64).unwrap();
+        assert!(a < b && f(a) < 0f64 && f(b) > 0f64);
+
+        assert_eq!(find_bracket(|x: f64| x - 1f64, 1f64, 1f64, &mut 1e-15f64), Ok((1f64, 2f64)));
+        let error = find_bracket(|x: f64| x * x + 1f64, 0f64, 1f64, &mut 1e-15f64).unwrap_err();
+        assert!(error.explored.0 < -1e5 && error.explored.1 > 1e5);
+        assert_eq!(SearchError::<Infallible>::from(error), SearchError::NoBracketing);
+        // 3 iterations cannot reach the root at 1000: [0, 1] grows to [0, 2.6], [0, 6.76] and [0, 17.576]
+        let mut convergency = SimpleConvergency { eps: 1e-15f64, max_iter: 3 };
+        let error = find_bracket(|x: f64| x - 1000f64, 0f64, 1f64, &mut convergency).unwrap_err();
+        assert_float_eq!(1e-13, error.explored.1, 17.576f64);
+        assert_eq!(error.explored.0, 0f64);
+    }
+
+    #[test]
+    fn test_find_bracket_in_grid() {
+        let f = |x: f64| (x - 2f64) * (x - 3f64);
+        assert_eq!(find_bracket_in_grid(f, 0f64, 10f64, 20), Ok((1.5f64, 2f64)));
+        assert_eq!(find_bracket_in_grid(f, 2.5f64, 10f64, 3), Ok((2.5f64, 5f64)));
+        assert_eq!(find_bracket_in_grid(f, -1f64, 9f64, 2), Err(SearchError::NoBracketing));
+        // More segments than i16 holds are clamped instead of wrapping around
+        assert_eq!(find_bracket_in_grid(f, 0f64, 32767f64, 40000), Ok((1f64, 2f64)));
+        assert_eq!(find_bracket_in_grid(|x: f64| x - 1f64, 0f64, 2f64, 0), Ok((0f64, 2f64)));
+    }
+}
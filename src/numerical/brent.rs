@@ -0,0 +1,381 @@
+// Copyright (c) 2015, Mikhail Vorotilov
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without
+// modification, are permitted provided that the following conditions are met:
+//
+// * Redistributions of source code must retain the above copyright notice, this
+//   list of conditions and the following disclaimer.
+//
+// * Redistributions in binary form must reproduce the above copyright notice,
+//   this list of conditions and the following disclaimer in the documentation
+//   and/or other materials provided with the distribution.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+// AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+// IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+// FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+// DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+// CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+// OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+// OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use super::super::Convergency;
+use super::super::FloatType;
+use super::super::SearchError;
+use super::Step;
+
+use core::cell::Cell;
+
+/// The Brent-Dekker method as a sequence of steps.
+///
+/// Inverse quadratic interpolation and the secant method are used while they
+/// shrink the bracket fast enough, otherwise the bracket is bisected.
+/// Steps end when the bracket cannot be split any more.
+///
+/// # Examples
+///
+/// ```
+/// use roots::Brent;
+///
+/// let solver = Brent::new(0f64, 1f64, |x: f64| x.cos() - x).unwrap();
+/// // Residuals after each step
+/// let residuals: Vec<f64> = solver.take(5).map(|step| step.y.abs()).collect();
+/// assert!(residuals[4] < 1e-12f64);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Brent<F: FloatType, Func> {
+    f: Func,
+    /// The previous estimate
+    a: F,
+    /// The best estimate
+    b: F,
+    /// The opposite end of the bracket
+    c: F,
+    fa: F,
+    fb: F,
+    fc: F,
+    /// The last step
+    d: F,
+    /// The step before the last one
+    e: F,
+    /// A root found exactly at an end of the initial bracket
+    root: Option<F>,
+    finished: bool,
+    /// Evaluations not reported by a step yet
+    evaluations: usize,
+}
+
+impl<F, Func> Brent<F, Func>
+where
+    F: FloatType,
+    Func: FnMut(F) -> F,
+{
+    /// Starts the search in the bracket [a, b]
+    ///
+    /// # Failures
+    /// ## NoBracketing
+    /// f(a) and f(b) have the same sign.
+    pub fn new(a: F, b: F, mut f: Func) -> Result<Self, SearchError> {
+        let (fa, fb) = (f(a), f(b));
+        let root = if fa == F::zero() {
+            Some(a)
+        } else if fb == F::zero() {
+            Some(b)
+        } else if (fa > F::zero()) == (fb > F::zero()) {
+            return Err(SearchError::NoBracketing);
+        } else {
+            None
+        };
+        let mut solver = Brent {
+            f,
+            a,
+            b,
+            c: a,
+            fa,
+            fb,
+            fc: fa,
+            d: b - a,
+            e: b - a,
+            root,
+            finished: false,
+            evaluations: 2,
+        };
+        solver.arrange();
+        Ok(solver)
+    }
+
+    /// Keeps the root between b and c, and b closer to the root
+    fn arrange(&mut self) {
+        if (self.fb > F::zero()) == (self.fc > F::zero()) {
+            self.c = self.a;
+            self.fc = self.fa;
+            self.d = self.b - self.a;
+            self.e = self.d;
+        }
+        if self.fc.abs() < self.fb.abs() {
+            self.a = self.b;
+            self.b = self.c;
+            self.c = self.a;
+            self.fa = self.fb;
+            self.fb = self.fc;
+            self.fc = self.fa;
+        }
+    }
+
+    /// The smallest step which still changes b
+    fn min_step(&self) -> F {
+        F::epsilon() * (self.b.abs() + F::epsilon())
+    }
+}
+
+impl<F, Func> Iterator for Brent<F, Func>
+where
+    F: FloatType,
+    Func: FnMut(F) -> F,
+{
+    type Item = Step<F>;
+
+    fn next(&mut self) -> Option<Step<F>> {
+        if self.finished {
+            return None;
+        }
+        if let Some(x) = self.root {
+            self.finished = true;
+            return Some(Step {
+                x,
+                y: F::zero(),
+                dx: F::zero(),
+                bracket: Some((x, x)),
+                evaluations: core::mem::take(&mut self.evaluations),
+                derivative_evaluations: 0,
+            });
+        }
+
+        let min_step = self.min_step();
+        let half = (self.c - self.b) / F::two();
+        if self.e.abs() >= min_step && self.fa.abs() > self.fb.abs() {
+            let s = self.fb / self.fa;
+            let (mut p, mut q) = if self.a == self.c {
+                // Secant
+                (F::two() * half * s, F::one() - s)
+            } else {
+                // Inverse quadratic interpolation
+                let q = self.fa / self.fc;
+                let r = self.fb / self.fc;
+                (
+                    s * (F::two() * half * q * (q - r) - (self.b - self.a) * (r - F::one())),
+                    (q - F::one()) * (r - F::one()) * (s - F::one()),
+                )
+            };
+            if p > F::zero() {
+                q = -q;
+            } else {
+                p = -p;
+            }
+            let (bound_1, bound_2) = (F::three() * half * q - (min_step * q).abs(), (self.e * q).abs());
+            if F::two() * p < bound_1 && F::two() * p < bound_2 {
+                self.e = self.d;
+                self.d = p / q;
+            } else {
+                self.d = half;
+                self.e = self.d;
+            }
+        } else {
+            self.d = half;
+            self.e = self.d;
+        }
+
+        self.a = self.b;
+        self.fa = self.fb;
+        self.b = if self.d.abs() > min_step {
+            self.b + self.d
+        } else if half > F::zero() {
+            self.b + min_step
+        } else {
+            self.b - min_step
+        };
+        self.fb = (self.f)(self.b);
+        self.evaluations += 1;
+        self.arrange();
+
+        self.finished = self.fb == F::zero() || ((self.c - self.b) / F::two()).abs() <= self.min_step();
+        Some(Step {
+            x: self.b,
+            y: self.fb,
+            dx: (self.c - self.b).abs(),
+            bracket: Some(super::ordered(self.b, self.c)),
+            evaluations: core::mem::take(&mut self.evaluations),
+            derivative_evaluations: 0,
+        })
+    }
+}
+
+/// Find a root of f(x)=0 in the bracket [a, b] by the Brent-Dekker method.
+///
+/// The Convergency receives the value of f and the width of the bracket.
+///
+/// # Failures
+/// ## NoBracketing
+/// f(a) and f(b) have the same sign.
+/// ## NoConvergency
+/// The algorithm cannot find a root within the given number of iterations.
+///
+/// # Examples
+///
+/// ```
+/// use roots::find_root_brent;
+///
+/// let f = |x: f64| x.cos() - x;
+/// let root = find_root_brent(0f64, 1f64, f, &mut 1e-15f64);
+/// // Returns approximately Ok(0.7390851332151607)
+/// ```
+pub fn find_root_brent<F, Func, Conv>(a: F, b: F, mut f: Func, convergency: &mut Conv) -> Result<F, SearchError>
+where
+    F: FloatType,
+    Func: FnMut(F) -> F,
+    Conv: Convergency<F>,
+{
+    find_root_brent_fallible(a, b, |x| Ok(f(x)), convergency)
+}
+
+/// Find a root of f(x)=0 in the bracket [a, b] by the Brent-Dekker method, where f may fail.
+///
+/// The search stops at the first error of f.
+///
+/// # Failures
+/// ## Function
+/// f has returned an error, which is passed on.
+/// ## NoBracketing
+/// f(a) and f(b) have the same sign.
+/// ## NoConvergency
+/// The algorithm cannot find a root within the given number of iterations.
+///
+/// # Examples
+///
+/// ```
+/// use roots::find_root_brent_fallible;
+/// use roots::SearchError;
+///
+/// let f = |x: f64| if x > 0f64 { Ok(x.ln() - 1f64) } else { Err("non-positive argument") };
+/// assert_eq!(find_root_brent_fallible(-1f64, 5f64, f, &mut 1e-15f64), Err(SearchError::Function("non-positive argument")));
+/// let root = find_root_brent_fallible(1f64, 5f64, f, &mut 1e-15f64);
+/// // Returns approximately Ok(2.718281828459045)
+/// ```
+pub fn find_root_brent_fallible<F, E, Func, Conv>(a: F, b: F, f: Func, convergency: &mut Conv) -> Result<F, SearchError<E>>
+where
+    F: FloatType,
+    Func: FnMut(F) -> Result<F, E>,
+    Conv: Convergency<F>,
+{
+    let error = Cell::new(None);
+    let solver = Brent::new(a, b, super::catching(f, &error, F::zero()));
+    super::solve(solver, convergency, &error)
+}
+
+/// Find a root of f(x)=0 near x0 by the Brent-Dekker method in a bracket found by find_bracket.
+///
+/// # Failures
+/// ## NoBracketing
+/// No sign change is found around x0 within the given number of iterations.
+/// ## NoConvergency
+/// The algorithm cannot find a root within the given number of iterations.
+///
+/// # Examples
+///
+/// ```
+/// use roots::find_root_brent_from;
+///
+/// let f = |x: f64| x.exp() - 1000f64;
+/// let root = find_root_brent_from(0f64, f, &mut 1e-15f64);
+/// // Returns approximately Ok(6.907755278982137)
+/// ```
+pub fn find_root_brent_from<F, Func, Conv>(x0: F, mut f: Func, convergency: &mut Conv) -> Result<F, SearchError>
+where
+    F: FloatType,
+    Func: FnMut(F) -> F,
+    Conv: Convergency<F>,
+{
+    let (a, b) = super::bracket::find_bracket(&mut f, x0, super::bracket::initial_step(x0), convergency)?;
+    find_root_brent(a, b, f, convergency)
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::super::*;
+
+    #[test]
+    fn test_find_root_brent() {
+        let f = |x: f64| x.cos() - x;
+        assert_float_eq!(1e-15, find_root_brent(0f64, 1f64, f, &mut 1e-15f64).unwrap(), 0.7390851332151607f64);
+        assert_float_eq!(1e-15, find_root_brent(1f64, 0f64, f, &mut 1e-15f64).unwrap(), 0.7390851332151607f64);
+        assert_eq!(find_root_brent(0f64, 1f64, |x: f64| x * x, &mut 1e-15f64), Ok(0f64));
+        assert_eq!(find_root_brent(1f64, 2f64, f, &mut 1e-15f64), Err(SearchError::NoBracketing));
+
+        // x^9 is very flat near the root; bisection steps keep the bracket shrinking
+        let mut convergency = SimpleConvergency { eps: 1e-12f64, max_iter: 200 };
+        let root = find_root_brent(-1f64, 1.5f64, |x: f64| x.powi(9), &mut convergency).unwrap();
+        assert!(root.powi(9).abs() < 1e-12f64);
+        assert_eq!(
+            find_root_brent(0f64, 1f64, f, &mut SimpleConvergency { eps: 0f64, max_iter: 2 }),
+            Err(SearchError::NoConvergency)
+        );
+    }
+
+    #[test]
+    fn test_brent_steps() {
+        let steps: Vec<Step<f64>> = Brent::new(0f64, 1f64, |x: f64| x.cos() - x).unwrap().collect();
+        for step in steps.iter() {
+            let (a, b) = step.bracket.unwrap();
+            assert!(a <= 0.7390851332151607f64 && 0.7390851332151607f64 <= b);
+        }
+        assert_float_eq!(1e-15, steps.last().unwrap().x, 0.7390851332151607f64);
+        assert!(steps.len() < 10);
+    }
+
+    #[test]
+    fn test_find_root_brent_from() {
+        let f = |x: f64| x.exp() - 1000f64;
+        assert_float_eq!(1e-14, find_root_brent_from(0f64, f, &mut 1e-15f64).unwrap(), 1000f64.ln());
+        assert_float_eq!(1e-14, find_root_brent_from(100f64, f, &mut 1e-15f64).unwrap(), 1000f64.ln());
+        assert_eq!(find_root_brent_from(0f64, |x: f64| x * x + 1f64, &mut 1e-15f64), Err(SearchError::NoBracketing));
+    }
+
+    #[test]
+    fn test_find_root_brent_fallible() {
+        let f = |x: f64| if x > 0f64 { Ok(x.ln() - 1f64) } else { Err("non-positive argument") };
+        assert_float_eq!(1e-15, find_root_brent_fallible(1f64, 5f64, f, &mut 1e-15f64).unwrap(), 1f64.exp());
+        assert_eq!(find_root_brent_fallible(-1f64, 5f64, f, &mut 1e-15f64), Err(SearchError::Function("non-positive argument")));
+        assert_eq!(find_root_brent_fallible(1f64, 2f64, f, &mut 1e-15f64), Err(SearchError::NoBracketing));
+
+        // An error in the middle of the search stops it, and no root is reported
+        let mut evaluations = 0;
+        let f = |x: f64| {
+            evaluations += 1;
+            if evaluations > 4 {
+                Err(evaluations)
+            } else {
+                Ok(x.cos() - x)
+            }
+        };
+        let mut stats = Stats::new(1e-15f64);
+        assert_eq!(find_root_brent_fallible(0f64, 1f64, f, &mut stats), Err(SearchError::Function(5)));
+        assert_eq!((stats.iterations, stats.evaluations, stats.root), (2, 4, None));
+
+        // FnMut closures are accepted by infallible solvers too
+        let mut evaluations = 0;
+        let root = find_root_brent(
+            0f64,
+            1f64,
+            |x: f64| {
+                evaluations += 1;
+                x.cos() - x
+            },
+            &mut 1e-15f64,
+        );
+        assert_float_eq!(1e-15, root.unwrap(), 0.7390851332151607f64);
+        assert!(evaluations > 2);
+    }
+}
@@ -0,0 +1,553 @@
+// Copyright (c) 2015, Mikhail Vorotilov
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without
+// modification, are permitted provided that the following conditions are met:
+//
+// * Redistributions of source code must retain the above copyright notice, this
+//   list of conditions and the following disclaimer.
+//
+// * Redistributions in binary form must reproduce the above copyright notice,
+//   this list of conditions and the following disclaimer in the documentation
+//   and/or other materials provided with the distribution.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+// AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+// IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+// FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+// DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+// CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+// OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+// OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Solvers of fixed-point problems x = g(x).
+//!
+//! Steps of the solvers in one variable report the residual g(x) - x at the last point
+//! where g was evaluated as y, and the distance between successive estimates as dx.
+
+use super::super::Convergency;
+use super::super::FloatType;
+use super::super::SearchError;
+use super::dot;
+use super::norm;
+use super::Step;
+
+use core::cell::Cell;
+
+/// Plain fixed-point iteration x = g(x) as a sequence of steps.
+///
+/// It converges linearly if |g'| < 1 near the fixed point.
+/// Steps end when x = g(x) exactly.
+///
+/// # Examples
+///
+/// ```
+/// use roots::FixedPointIteration;
+///
+/// let estimates: Vec<f64> = FixedPointIteration::new(0f64, |x: f64| x / 2f64 + 1f64).take(3).map(|step| step.x).collect();
+/// assert_eq!(estimates, vec![1f64, 1.5f64, 1.75f64]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct FixedPointIteration<F: FloatType, Func> {
+    g: Func,
+    x: F,
+    finished: bool,
+}
+
+impl<F, Func> FixedPointIteration<F, Func>
+where
+    F: FloatType,
+    Func: FnMut(F) -> F,
+{
+    /// Starts the iteration at x0
+    pub fn new(x0: F, g: Func) -> Self {
+        FixedPointIteration {
+            g,
+            x: x0,
+            finished: false,
+        }
+    }
+}
+
+impl<F, Func> Iterator for FixedPointIteration<F, Func>
+where
+    F: FloatType,
+    Func: FnMut(F) -> F,
+{
+    type Item = Step<F>;
+
+    fn next(&mut self) -> Option<Step<F>> {
+        if self.finished {
+            return None;
+        }
+        let gx = (self.g)(self.x);
+        let residual = gx - self.x;
+        self.finished = residual == F::zero();
+        self.x = gx;
+        Some(Step {
+            x: gx,
+            y: residual,
+            dx: residual.abs(),
+            bracket: None,
+            evaluations: 1,
+            derivative_evaluations: 0,
+        })
+    }
+}
+
+/// Accelerated estimate x0 - (x1 - x0)^2 / (x2 - 2*x1 + x0) of the limit of x0, x1, x2,
+/// or x2 if the differences do not shrink geometrically
+fn aitken<F: FloatType>(x0: F, x1: F, x2: F) -> F {
+    let denominator = x2 - F::two() * x1 + x0;
+    if denominator == F::zero() {
+        x2
+    } else {
+        x0 - (x1 - x0) * (x1 - x0) / denominator
+    }
+}
+
+/// Fixed-point iteration x = g(x) accelerated by the Aitken delta-squared process, as a sequence of steps.
+///
+/// The plain iteration goes on unchanged, and each step reports the limit
+/// extrapolated from its last three values. Each step evaluates g once, after
+/// two evaluations at the start. Steps end when x = g(x) exactly.
+///
+/// # Examples
+///
+/// ```
+/// use roots::Aitken;
+///
+/// // The plain iteration converges to 0.6180339887498949 slowly
+/// let estimates: Vec<f64> = Aitken::new(1f64, |x: f64| 1f64 / (1f64 + x)).take(3).map(|step| step.x).collect();
+/// assert_eq!(estimates, vec![0.625f64, 0.6190476190476191f64, 0.6181818181818182f64]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Aitken<F: FloatType, Func> {
+    g: Func,
+    /// The last three values of the plain iteration
+    x0: F,
+    x1: F,
+    x2: F,
+    /// The last accelerated estimate
+    estimate: F,
+    started: bool,
+    finished: bool,
+}
+
+impl<F, Func> Aitken<F, Func>
+where
+    F: FloatType,
+    Func: FnMut(F) -> F,
+{
+    /// Starts the iteration at x0
+    pub fn new(x0: F, mut g: Func) -> Self {
+        let x1 = g(x0);
+        let x2 = g(x1);
+        Aitken {
+            g,
+            x0,
+            x1,
+            x2,
+            estimate: x0,
+            started: false,
+            finished: false,
+        }
+    }
+}
+
+impl<F, Func> Iterator for Aitken<F, Func>
+where
+    F: FloatType,
+    Func: FnMut(F) -> F,
+{
+    type Item = Step<F>;
+
+    fn next(&mut self) -> Option<Step<F>> {
+        if self.finished {
+            return None;
+        }
+        let evaluations = if self.started {
+            self.x0 = self.x1;
+            self.x1 = self.x2;
+            self.x2 = (self.g)(self.x1);
+            1
+        } else {
+            self.started = true;
+            2
+        };
+        let residual = self.x2 - self.x1;
+        let estimate = if residual == F::zero() {
+            self.finished = true;
+            self.x2
+        } else {
+            aitken(self.x0, self.x1, self.x2)
+        };
+        let dx = (estimate - self.estimate).abs();
+        self.estimate = estimate;
+        Some(Step {
+            x: estimate,
+            y: residual,
+            dx,
+            bracket: None,
+            evaluations,
+            derivative_evaluations: 0,
+        })
+    }
+}
+
+/// Steffensen's method for x = g(x) as a sequence of steps.
+///
+/// Each step extrapolates x, g(x), g(g(x)) by the Aitken delta-squared process
+/// and restarts the iteration from the result. It converges quadratically near
+/// a simple fixed point without derivatives, evaluating g twice per step.
+/// Steps end when x = g(x) exactly.
+///
+/// # Examples
+///
+/// ```
+/// use roots::Steffensen;
+///
+/// // g'(x) = -2 at the fixed point x = 1, so the plain iteration diverges
+/// let steps: Vec<f64> = Steffensen::new(0.5f64, |x: f64| 3f64 - 2f64 * x).take(1).map(|step| step.x).collect();
+/// assert_eq!(steps, vec![1f64]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Steffensen<F: FloatType, Func> {
+    g: Func,
+    x: F,
+    finished: bool,
+}
+
+impl<F, Func> Steffensen<F, Func>
+where
+    F: FloatType,
+    Func: FnMut(F) -> F,
+{
+    /// Starts the iteration at x0
+    pub fn new(x0: F, g: Func) -> Self {
+        Steffensen {
+            g,
+            x: x0,
+            finished: false,
+        }
+    }
+}
+
+impl<F, Func> Iterator for Steffensen<F, Func>
+where
+    F: FloatType,
+    Func: FnMut(F) -> F,
+{
+    type Item = Step<F>;
+
+    fn next(&mut self) -> Option<Step<F>> {
+        if self.finished {
+            return None;
+        }
+        let x = self.x;
+        let gx = (self.g)(x);
+        let residual = gx - x;
+        let (x_new, evaluations) = if residual == F::zero() {
+            self.finished = true;
+            (x, 1)
+        } else {
+            (aitken(x, gx, (self.g)(gx)), 2)
+        };
+        self.x = x_new;
+        Some(Step {
+            x: x_new,
+            y: residual,
+            dx: (x_new - x).abs(),
+            bracket: None,
+            evaluations,
+            derivative_evaluations: 0,
+        })
+    }
+}
+
+/// Find a fixed point x = g(x) by plain iteration starting from x0.
+///
+/// The Convergency receives the residual g(x) - x and the size of the last step.
+///
+/// # Failures
+/// ## NoConvergency
+/// The iteration does not converge within the given number of iterations.
+///
+/// # Examples
+///
+/// ```
+/// use roots::find_fixed_point;
+///
+/// use roots::SimpleConvergency;
+///
+/// let root = find_fixed_point(1f64, |x: f64| x.cos(), &mut SimpleConvergency { eps: 1e-15f64, max_iter: 100 });
+/// // Returns approximately Ok(0.7390851332151607)
+/// ```
+pub fn find_fixed_point<F, Func, Conv>(x0: F, g: Func, convergency: &mut Conv) -> Result<F, SearchError>
+where
+    F: FloatType,
+    Func: FnMut(F) -> F,
+    Conv: Convergency<F>,
+{
+    super::solve(Ok(FixedPointIteration::new(x0, g)), convergency, &Cell::new(None))
+}
+
+/// Find a fixed point x = g(x) by the iteration accelerated by the Aitken delta-squared process.
+///
+/// The Convergency receives the residual g(x) - x of the plain iteration and the change of the accelerated estimate.
+///
+/// # Failures
+/// ## NoConvergency
+/// The iteration does not converge within the given number of iterations.
+///
+/// # Examples
+///
+/// ```
+/// use roots::find_fixed_point_aitken;
+///
+/// use roots::SimpleConvergency;
+///
+/// let root = find_fixed_point_aitken(1f64, |x: f64| x.cos(), &mut SimpleConvergency { eps: 1e-15f64, max_iter: 100 });
+/// // Returns approximately Ok(0.7390851332151607)
+/// ```
+pub fn find_fixed_point_aitken<F, Func, Conv>(x0: F, g: Func, convergency: &mut Conv) -> Result<F, SearchError>
+where
+    F: FloatType,
+    Func: FnMut(F) -> F,
+    Conv: Convergency<F>,
+{
+    super::solve(Ok(Aitken::new(x0, g)), convergency, &Cell::new(None))
+}
+
+/// Find a fixed point x = g(x) by Steffensen's method.
+///
+/// The Convergency receives the residual g(x) - x and the size of the last step.
+///
+/// # Failures
+/// ## NoConvergency
+/// The method does not converge within the given number of iterations.
+///
+/// # Examples
+///
+/// ```
+/// use roots::find_fixed_point_steffensen;
+///
+/// let root = find_fixed_point_steffensen(1f64, |x: f64| x.cos(), &mut 1e-15f64);
+/// // Returns approximately Ok(0.7390851332151607)
+/// ```
+pub fn find_fixed_point_steffensen<F, Func, Conv>(x0: F, g: Func, convergency: &mut Conv) -> Result<F, SearchError>
+where
+    F: FloatType,
+    Func: FnMut(F) -> F,
+    Conv: Convergency<F>,
+{
+    super::solve(Ok(Steffensen::new(x0, g)), convergency, &Cell::new(None))
+}
+
+/// Coefficients gamma minimizing |f - sum of gamma[j] * columns[j]| by the modified Gram-Schmidt process.
+///
+/// Columns are taken from the last one; a column nearly dependent on the later ones
+/// is dropped together with the earlier ones, and gets the coefficient 0.
+fn least_squares<F: FloatType, const N: usize>(columns: &[[F; N]], f: &[F; N]) -> Vec<F> {
+    let tolerance = F::epsilon().sqrt();
+    let mut q: Vec<[F; N]> = Vec::new();
+    // Columns of the upper triangular factor, r[k][k] is the diagonal element
+    let mut r: Vec<Vec<F>> = Vec::new();
+    for column in columns.iter().rev() {
+        let mut v = *column;
+        let mut r_column = Vec::new();
+        for q_k in q.iter() {
+            let projection = dot(q_k, &v);
+            for (v_i, &q_ki) in v.iter_mut().zip(q_k.iter()) {
+                *v_i = *v_i - projection * q_ki;
+            }
+            r_column.push(projection);
+        }
+        let diagonal = norm(&v);
+        if diagonal == F::zero() || diagonal <= tolerance * norm(column) {
+            break;
+        }
+        for v_i in v.iter_mut() {
+            *v_i = *v_i / diagonal;
+        }
+        r_column.push(diagonal);
+        q.push(v);
+        r.push(r_column);
+    }
+
+    // Back substitution in R c = Q^T f
+    let mut c: Vec<F> = q.iter().map(|q_k| dot(q_k, f)).collect();
+    for k in (0..c.len()).rev() {
+        c[k] = c[k] / r[k][k];
+        for i in 0..k {
+            c[i] = c[i] - r[k][i] * c[k];
+        }
+    }
+    let mut gamma = vec![F::zero(); columns.len()];
+    for (k, &c_k) in c.iter().enumerate() {
+        gamma[columns.len() - 1 - k] = c_k;
+    }
+    gamma
+}
+
+/// Difference a - b of two vectors
+fn difference<F: FloatType, const N: usize>(a: &[F; N], b: &[F; N]) -> [F; N] {
+    let mut result = *a;
+    for (r, &b_i) in result.iter_mut().zip(b.iter()) {
+        *r = *r - b_i;
+    }
+    result
+}
+
+/// Find a fixed point of a system x = G(x) of N equations by Anderson mixing.
+///
+/// The next estimate combines the last memory + 1 values of G so that the combination
+/// of their residuals G(x) - x has the least norm; memory 0 is the plain iteration.
+/// Anderson mixing is equivalent to a multisecant quasi-Newton method, and needs no derivatives.
+/// Use [x] with N = 1 for equations in one variable.
+/// The Convergency receives the norm of the residual G(x) - x and the norm of the last step.
+///
+/// # Failures
+/// ## NoConvergency
+/// The algorithm cannot find a fixed point within the given number of iterations.
+///
+/// # Examples
+///
+/// ```
+/// use roots::find_fixed_point_anderson;
+///
+/// // x = cos(y); y = sin(x) / 2
+/// let g = |v: &[f64; 2]| [v[1].cos(), v[0].sin() / 2f64];
+///
+/// let root = find_fixed_point_anderson([0f64, 0f64], g, 3, &mut 1e-14f64);
+/// // Returns approximately Ok([0.9217141291315096, 0.3983194523366731])
+/// ```
+pub fn find_fixed_point_anderson<F, Func, Conv, const N: usize>(
+    start: [F; N],
+    mut g: Func,
+    memory: usize,
+    convergency: &mut Conv,
+) -> Result<[F; N], SearchError>
+where
+    F: FloatType,
+    Func: FnMut(&[F; N]) -> [F; N],
+    Conv: Convergency<F>,
+{
+    let mut x = start;
+    let mut gx = g(&x);
+    let mut fx = difference(&gx, &x);
+    // Differences between successive residuals and values of G, the latest last
+    let mut df: Vec<[F; N]> = Vec::new();
+    let mut dg: Vec<[F; N]> = Vec::new();
+    let mut iter = 0;
+    loop {
+        if convergency.is_root_found(norm(&fx)) {
+            return Ok(x);
+        }
+
+        let gamma = least_squares(&df, &fx);
+        let mut x_new = gx;
+        for (&gamma_j, dg_j) in gamma.iter().zip(dg.iter()) {
+            for (x_i, &dg_ji) in x_new.iter_mut().zip(dg_j.iter()) {
+                *x_i = *x_i - gamma_j * dg_ji;
+            }
+        }
+        let gx_new = g(&x_new);
+        let fx_new = difference(&gx_new, &x_new);
+        let dx_norm = norm(&difference(&x_new, &x));
+
+        if memory > 0 {
+            if df.len() == memory {
+                df.remove(0);
+                dg.remove(0);
+            }
+            df.push(difference(&fx_new, &fx));
+            dg.push(difference(&gx_new, &gx));
+        }
+        x = x_new;
+        gx = gx_new;
+        fx = fx_new;
+
+        if convergency.is_converged(dx_norm) {
+            return Ok(x);
+        }
+
+        iter += 1;
+        if convergency.is_iteration_limit_reached(iter) {
+            return Err(SearchError::NoConvergency);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::super::*;
+
+    #[test]
+    fn test_find_fixed_point() {
+        let mut convergency = SimpleConvergency { eps: 1e-15f64, max_iter: 100 };
+        assert_float_eq!(1e-15, find_fixed_point(1f64, |x: f64| x.cos(), &mut convergency).unwrap(), 0.7390851332151607f64);
+        // |g'| = 2 at the fixed point
+        assert_eq!(find_fixed_point(0.5f64, |x: f64| 3f64 - 2f64 * x, &mut 1e-15f64), Err(SearchError::NoConvergency));
+
+        // The start point is a fixed point
+        let steps: Vec<Step<f64>> = FixedPointIteration::new(2f64, |x: f64| x * x - 2f64).collect();
+        assert_eq!(steps.len(), 1);
+        assert_eq!((steps[0].x, steps[0].y), (2f64, 0f64));
+    }
+
+    #[test]
+    fn test_find_fixed_point_aitken() {
+        let mut stats = Stats::new(SimpleConvergency { eps: 1e-15f64, max_iter: 100 });
+        let root = find_fixed_point_aitken(1f64, |x: f64| x.cos(), &mut stats).unwrap();
+        assert_float_eq!(1e-15, root, 0.7390851332151607f64);
+        assert!(stats.iterations < 50);
+        assert_eq!(stats.evaluations, stats.iterations + 1);
+
+        let steps: Vec<Step<f64>> = Aitken::new(2f64, |x: f64| x * x - 2f64).collect();
+        assert_eq!(steps.len(), 1);
+        assert_eq!(find_fixed_point_aitken(0.5f64, |x: f64| 3f64 - 2f64 * x, &mut 1e-15f64), Ok(1f64));
+    }
+
+    #[test]
+    fn test_find_fixed_point_steffensen() {
+        let mut stats = Stats::new(1e-15f64);
+        let root = find_fixed_point_steffensen(1f64, |x: f64| x.cos(), &mut stats).unwrap();
+        assert_float_eq!(1e-15, root, 0.7390851332151607f64);
+        assert_eq!((stats.iterations, stats.evaluations), (5, 9));
+
+        // The plain iteration diverges, the extrapolation of a linear map is exact
+        assert_eq!(find_fixed_point_steffensen(0.5f64, |x: f64| 3f64 - 2f64 * x, &mut 1e-15f64), Ok(1f64));
+        let steps: Vec<Step<f64>> = Steffensen::new(0.5f64, |x: f64| 3f64 - 2f64 * x).collect();
+        assert_eq!(steps.iter().map(|step| step.x).collect::<Vec<f64>>(), vec![1f64, 1f64]);
+    }
+
+    #[test]
+    fn test_find_fixed_point_anderson() {
+        let g = |v: &[f64; 2]| [v[1].cos(), v[0].sin() / 2f64];
+        for memory in 1..4 {
+            let root = find_fixed_point_anderson([0f64, 0f64], g, memory, &mut 1e-14f64).unwrap();
+            assert_float_array_eq!(1e-14, root.iter().cloned(), [0.9217141291315096f64, 0.3983194523366731f64]);
+        }
+
+        // A nearly linear map with |G'| close to 1: the plain iteration is too slow
+        let g = |v: &[f64; 10]| {
+            let mut result = [0f64; 10];
+            for (i, r) in result.iter_mut().enumerate() {
+                *r = 0.49f64 * (v[(i + 1) % 10] + v[(i + 9) % 10]) + 1f64 + 0.01f64 * v[i].sin();
+            }
+            result
+        };
+        let mut convergency = SimpleConvergency { eps: 1e-12f64, max_iter: 1000 };
+        assert_eq!(find_fixed_point_anderson([0f64; 10], g, 0, &mut convergency), Err(SearchError::NoConvergency));
+        let mut convergency = SimpleConvergency { eps: 1e-12f64, max_iter: 20 };
+        let root = find_fixed_point_anderson([0f64; 10], g, 5, &mut convergency).unwrap();
+        assert_float_array_eq!(1e-10, g(&root).iter().cloned(), root);
+
+        // One variable
+        let root = find_fixed_point_anderson([1f64], |v: &[f64; 1]| [v[0].cos()], 1, &mut 1e-15f64).unwrap();
+        assert_float_eq!(1e-15, root[0], 0.7390851332151607f64);
+    }
+}
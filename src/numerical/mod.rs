@@ -0,0 +1,156 @@
+// Copyright (c) 2015, Mikhail Vorotilov
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without
+// modification, are permitted provided that the following conditions are met:
+//
+// * Redistributions of source code must retain the above copyright notice, this
+//   list of conditions and the following disclaimer.
+//
+// * Redistributions in binary form must reproduce the above copyright notice,
+//   this list of conditions and the following disclaimer in the documentation
+//   and/or other materials provided with the distribution.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+// AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+// IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+// FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+// DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+// CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+// OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+// OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Iterative solvers for equations f(x)=0 in one variable given by closures.
+//!
+//! Each iterative method is a state machine implementing `Iterator<Item = Step<F>>`,
+//! so a search can be driven, inspected, paused and resumed step by step.
+//! Functions find_root_* run such a state machine to the end.
+//!
+//! Fixed-point problems x = g(x) are solved by fixed_point, which also includes
+//! Anderson mixing for systems.
+
+pub mod bracket;
+pub mod brent;
+pub mod fixed_point;
+pub mod newton_bracketed;
+pub mod newton_raphson;
+pub mod regula_falsi;
+pub mod secant;
+pub mod subdivision;
+
+use super::Convergency;
+use super::FloatType;
+use super::SearchError;
+
+use core::cell::Cell;
+
+/// State of an iterative solver after one iteration
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Step<F: FloatType> {
+    /// The current estimate of the root
+    pub x: F,
+    /// Value of the function at x
+    pub y: F,
+    /// The size of the last step, or the width of the bracket for bracketing methods;
+    /// this is the value passed to Convergency::is_converged
+    pub dx: F,
+    /// Ordered ends of the interval known to contain the root, if the method keeps one
+    pub bracket: Option<(F, F)>,
+    /// Number of evaluations of the function made by this step; the first step also counts
+    /// evaluations made when the solver was created
+    pub evaluations: usize,
+    /// Number of evaluations of the derivative made by this step, counted like evaluations
+    pub derivative_evaluations: usize,
+}
+
+/// Wraps a fallible function for the solvers.
+///
+/// The first error is stored in error, and value is returned instead of the result,
+/// so that the solver stops at this step; solve reports the stored error afterwards.
+pub(crate) fn catching<'a, X, Y, E, Func>(mut f: Func, error: &'a Cell<Option<E>>, value: Y) -> impl FnMut(X) -> Y + 'a
+where
+    Y: Copy + 'a,
+    E: 'a,
+    Func: FnMut(X) -> Result<Y, E> + 'a,
+{
+    move |x| match f(x) {
+        Ok(y) => y,
+        Err(e) => {
+            let first = error.take().unwrap_or(e);
+            error.set(Some(first));
+            value
+        }
+    }
+}
+
+/// Runs a solver until the convergency accepts a step, reporting steps and the result to the convergency.
+///
+/// If the solver stops by itself, because the estimate cannot be refined any more, the last estimate is returned.
+/// An error stored by the function wrapped with catching stops the search before its step is reported.
+pub(crate) fn solve<F, E, I, Conv>(
+    solver: Result<I, SearchError>,
+    convergency: &mut Conv,
+    error: &Cell<Option<E>>,
+) -> Result<F, SearchError<E>>
+where
+    F: FloatType,
+    I: Iterator<Item = Step<F>>,
+    Conv: Convergency<F>,
+{
+    let result = match error.take() {
+        Some(e) => Err(SearchError::Function(e)),
+        None => match solver {
+            Ok(steps) => run(steps, convergency, error),
+            Err(e) => Err(e.lift()),
+        },
+    };
+    convergency.on_result(result.as_ref().ok().copied());
+    result
+}
+
+fn run<F, E, I, Conv>(steps: I, convergency: &mut Conv, error: &Cell<Option<E>>) -> Result<F, SearchError<E>>
+where
+    F: FloatType,
+    I: Iterator<Item = Step<F>>,
+    Conv: Convergency<F>,
+{
+    let mut last = None;
+    for (iter, step) in steps.enumerate() {
+        if let Some(e) = error.take() {
+            return Err(SearchError::Function(e));
+        }
+        convergency.on_iteration(&step);
+        if convergency.is_root_found(step.y) || convergency.is_converged(step.dx) {
+            return Ok(step.x);
+        }
+        last = Some(step.x);
+        if convergency.is_iteration_limit_reached(iter + 1) {
+            return Err(SearchError::NoConvergency);
+        }
+    }
+    match error.take() {
+        Some(e) => Err(SearchError::Function(e)),
+        None => last.ok_or(SearchError::NoConvergency),
+    }
+}
+
+/// Orders the ends of an interval
+fn ordered<F: FloatType>(a: F, b: F) -> (F, F) {
+    if a < b {
+        (a, b)
+    } else {
+        (b, a)
+    }
+}
+
+/// Dot product of two vectors
+pub(crate) fn dot<F: FloatType, const N: usize>(a: &[F; N], b: &[F; N]) -> F {
+    a.iter().zip(b.iter()).fold(F::zero(), |acc, (&x, &y)| acc + x * y)
+}
+
+/// Euclidean norm of a vector
+pub(crate) fn norm<F: FloatType, const N: usize>(v: &[F; N]) -> F {
+    v.iter().fold(F::zero(), |acc, &x| acc + x * x).sqrt()
+}
@@ -0,0 +1,300 @@
+// Copyright (c) 2015, Mikhail Vorotilov
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without
+// modification, are permitted provided that the following conditions are met:
+//
+// * Redistributions of source code must retain the above copyright notice, this
+//   list of conditions and the following disclaimer.
+//
+// * Redistributions in binary form must reproduce the above copyright notice,
+//   this list of conditions and the following disclaimer in the documentation
+//   and/or other materials provided with the distribution.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+// AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+// IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+// FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+// DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+// CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+// OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+// OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use super::super::Convergency;
+use super::super::FloatType;
+use super::super::SearchError;
+use super::Step;
+
+use core::cell::Cell;
+
+/// The Newton method safeguarded by bisection as a sequence of steps.
+///
+/// f_and_df returns the value of the function and its derivative at x.
+/// The bracket around the root shrinks at every iteration: the Newton step is taken
+/// only if it stays inside the bracket and decreases the step fast enough,
+/// otherwise the bracket is bisected (see rtsafe in Numerical Recipes).
+/// Steps end when the estimate cannot be changed any more.
+///
+/// # Examples
+///
+/// ```
+/// use roots::NewtonBracketed;
+///
+/// let f_and_df = |x: f64| (x * x - 2f64, 2f64 * x);
+/// for step in NewtonBracketed::new(0f64, 2f64, f_and_df).unwrap().take(4) {
+///     // Every estimate stays in the bracket
+///     let (a, b) = step.bracket.unwrap();
+///     assert!(a <= step.x && step.x <= b);
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct NewtonBracketed<F: FloatType, Func> {
+    f_and_df: Func,
+    /// f(low) < 0
+    low: F,
+    /// f(high) > 0
+    high: F,
+    x: F,
+    fx: F,
+    dfx: F,
+    dx: F,
+    dx_old: F,
+    /// A root found exactly at an end or in the middle of the initial bracket
+    root: Option<F>,
+    finished: bool,
+    /// Evaluations of f and its derivative not reported by a step yet
+    evaluations: usize,
+}
+
+impl<F, Func> NewtonBracketed<F, Func>
+where
+    F: FloatType,
+    Func: FnMut(F) -> (F, F),
+{
+    /// Starts the search in the bracket [a, b] from its middle point
+    ///
+    /// # Failures
+    /// ## NoBracketing
+    /// f(a) and f(b) have the same sign.
+    pub fn new(a: F, b: F, mut f_and_df: Func) -> Result<Self, SearchError> {
+        let (fa, _) = f_and_df(a);
+        let (fb, _) = f_and_df(b);
+        if fa != F::zero() && fb != F::zero() && (fa > F::zero()) == (fb > F::zero()) {
+            return Err(SearchError::NoBracketing);
+        }
+
+        let (mut low, mut high) = if fa < F::zero() { (a, b) } else { (b, a) };
+        let x = (a + b) / F::two();
+        let (fx, dfx) = f_and_df(x);
+        if fx < F::zero() {
+            low = x;
+        } else {
+            high = x;
+        }
+        let root = if fa == F::zero() {
+            Some(a)
+        } else if fb == F::zero() {
+            Some(b)
+        } else if fx == F::zero() {
+            Some(x)
+        } else {
+            None
+        };
+        Ok(NewtonBracketed {
+            f_and_df,
+            low,
+            high,
+            x,
+            fx,
+            dfx,
+            dx: (b - a).abs(),
+            dx_old: (b - a).abs(),
+            root,
+            finished: false,
+            evaluations: 3,
+        })
+    }
+}
+
+impl<F, Func> Iterator for NewtonBracketed<F, Func>
+where
+    F: FloatType,
+    Func: FnMut(F) -> (F, F),
+{
+    type Item = Step<F>;
+
+    fn next(&mut self) -> Option<Step<F>> {
+        if self.finished {
+            return None;
+        }
+        if let Some(x) = self.root {
+            self.finished = true;
+            let evaluations = core::mem::take(&mut self.evaluations);
+            return Some(Step {
+                x,
+                y: F::zero(),
+                dx: F::zero(),
+                bracket: Some((x, x)),
+                evaluations,
+                derivative_evaluations: evaluations,
+            });
+        }
+
+        let (x, fx, dfx) = (self.x, self.fx, self.dfx);
+        let out_of_bracket = ((x - self.high) * dfx - fx) * ((x - self.low) * dfx - fx) > F::zero();
+        let too_slow = (F::two() * fx).abs() > (self.dx_old * dfx).abs();
+        self.dx_old = self.dx;
+        if out_of_bracket || too_slow {
+            self.dx = (self.high - self.low) / F::two();
+            self.x = self.low + self.dx;
+        } else {
+            self.dx = fx / dfx;
+            self.x = x - self.dx;
+        }
+
+        let (fx, dfx) = (self.f_and_df)(self.x);
+        self.evaluations += 1;
+        self.fx = fx;
+        self.dfx = dfx;
+        if fx < F::zero() {
+            self.low = self.x;
+        } else {
+            self.high = self.x;
+        }
+
+        let evaluations = core::mem::take(&mut self.evaluations);
+        let middle = (self.low + self.high) / F::two();
+        self.finished = fx == F::zero() || self.dx == F::zero() || middle == self.low || middle == self.high;
+        Some(Step {
+            x: self.x,
+            y: fx,
+            dx: self.dx.abs(),
+            bracket: Some(super::ordered(self.low, self.high)),
+            evaluations,
+            derivative_evaluations: evaluations,
+        })
+    }
+}
+
+/// Find a root of f(x)=0 in the bracket [a, b] by the Newton method safeguarded by bisection.
+///
+/// f_and_df returns the value of the function and its derivative at x.
+/// The Convergency receives the value of f and the size of the last step.
+///
+/// # Failures
+/// ## NoBracketing
+/// f(a) and f(b) have the same sign.
+/// ## NoConvergency
+/// The algorithm cannot find a root within the given number of iterations.
+///
+/// # Examples
+///
+/// ```
+/// use roots::find_root_newton_bracketed;
+///
+/// // Plain Newton started at 0 diverges on atan(x - 1)
+/// let f_and_df = |x: f64| ((x - 1f64).atan(), 1f64 / (1f64 + (x - 1f64) * (x - 1f64)));
+///
+/// let root = find_root_newton_bracketed(-10f64, 30f64, f_and_df, &mut 1e-15f64);
+/// // Returns approximately Ok(1)
+/// ```
+pub fn find_root_newton_bracketed<F, Func, Conv>(a: F, b: F, mut f_and_df: Func, convergency: &mut Conv) -> Result<F, SearchError>
+where
+    F: FloatType,
+    Func: FnMut(F) -> (F, F),
+    Conv: Convergency<F>,
+{
+    find_root_newton_bracketed_fallible(a, b, |x| Ok(f_and_df(x)), convergency)
+}
+
+/// Find a root of f(x)=0 in the bracket [a, b] by the safeguarded Newton method, where f may fail.
+///
+/// f_and_df returns the value of the function and its derivative at x, or an error.
+/// The search stops at the first error.
+///
+/// # Failures
+/// ## Function
+/// f_and_df has returned an error, which is passed on.
+/// ## NoBracketing
+/// f(a) and f(b) have the same sign.
+/// ## NoConvergency
+/// The algorithm cannot find a root within the given number of iterations.
+pub fn find_root_newton_bracketed_fallible<F, E, Func, Conv>(
+    a: F,
+    b: F,
+    f_and_df: Func,
+    convergency: &mut Conv,
+) -> Result<F, SearchError<E>>
+where
+    F: FloatType,
+    Func: FnMut(F) -> Result<(F, F), E>,
+    Conv: Convergency<F>,
+{
+    let error = Cell::new(None);
+    let solver = NewtonBracketed::new(a, b, super::catching(f_and_df, &error, (F::zero(), F::zero())));
+    super::solve(solver, convergency, &error)
+}
+
+/// Find a root of f(x)=0 near x0 by the safeguarded Newton method in a bracket found by find_bracket.
+///
+/// # Failures
+/// ## NoBracketing
+/// No sign change is found around x0 within the given number of iterations.
+/// ## NoConvergency
+/// The algorithm cannot find a root within the given number of iterations.
+pub fn find_root_newton_bracketed_from<F, Func, Conv>(x0: F, mut f_and_df: Func, convergency: &mut Conv) -> Result<F, SearchError>
+where
+    F: FloatType,
+    Func: FnMut(F) -> (F, F),
+    Conv: Convergency<F>,
+{
+    let (a, b) = super::bracket::find_bracket(|x| f_and_df(x).0, x0, super::bracket::initial_step(x0), convergency)?;
+    find_root_newton_bracketed(a, b, f_and_df, convergency)
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::super::*;
+
+    #[test]
+    fn test_find_root_newton_bracketed() {
+        let f_and_df = |x: f64| ((x - 1f64).atan(), 1f64 / (1f64 + (x - 1f64) * (x - 1f64)));
+        assert_float_eq!(1e-15, find_root_newton_bracketed(-10f64, 30f64, f_and_df, &mut 1e-15f64).unwrap(), 1f64);
+        assert_float_eq!(1e-15, find_root_newton_bracketed(30f64, -10f64, f_and_df, &mut 1e-15f64).unwrap(), 1f64);
+
+        // The derivative vanishes at the start point x = 0
+        let f_and_df = |x: f64| (x * x * x - 8f64, 3f64 * x * x);
+        assert_float_eq!(1e-14, find_root_newton_bracketed(-4f64, 4f64, f_and_df, &mut 1e-15f64).unwrap(), 2f64);
+        assert_eq!(find_root_newton_bracketed(2f64, 3f64, f_and_df, &mut 1e-15f64), Ok(2f64));
+
+        // The error is met at the midpoint of the bracket
+        let f_and_df = |x: f64| if x != 0f64 { Ok((x * x * x - 8f64, 3f64 * x * x)) } else { Err("zero") };
+        assert_float_eq!(1e-14, find_root_newton_bracketed_fallible(-3f64, 4f64, f_and_df, &mut 1e-15f64).unwrap(), 2f64);
+        assert_eq!(find_root_newton_bracketed_fallible(-4f64, 4f64, f_and_df, &mut 1e-15f64), Err(SearchError::Function("zero")));
+    }
+
+    #[test]
+    fn test_find_root_newton_bracketed_failures() {
+        let f_and_df = |x: f64| (x * x - 2f64, 2f64 * x);
+        assert_eq!(
+            find_root_newton_bracketed(2f64, 3f64, f_and_df, &mut 1e-15f64),
+            Err(SearchError::NoBracketing)
+        );
+        assert_eq!(
+            find_root_newton_bracketed(0f64, 100f64, f_and_df, &mut SimpleConvergency { eps: 0f64, max_iter: 3 }),
+            Err(SearchError::NoConvergency)
+        );
+    }
+
+    #[test]
+    fn test_find_root_newton_bracketed_from() {
+        let f_and_df = |x: f64| ((x - 1f64).atan(), 1f64 / (1f64 + (x - 1f64) * (x - 1f64)));
+        assert_float_eq!(1e-15, find_root_newton_bracketed_from(-20f64, f_and_df, &mut 1e-15f64).unwrap(), 1f64);
+        assert_eq!(
+            find_root_newton_bracketed_from(0f64, |x: f64| (x * x + 1f64, 2f64 * x), &mut 1e-15f64),
+            Err(SearchError::NoBracketing)
+        );
+    }
+}
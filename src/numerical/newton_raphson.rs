@@ -0,0 +1,240 @@
+// Copyright (c) 2015, Mikhail Vorotilov
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without
+// modification, are permitted provided that the following conditions are met:
+//
+// * Redistributions of source code must retain the above copyright notice, this
+//   list of conditions and the following disclaimer.
+//
+// * Redistributions in binary form must reproduce the above copyright notice,
+//   this list of conditions and the following disclaimer in the documentation
+//   and/or other materials provided with the distribution.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+// AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+// IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+// FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+// DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+// CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+// OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+// OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+use super::super::Convergency;
+use super::super::FloatType;
+use super::super::SearchError;
+use super::Step;
+
+use core::cell::Cell;
+
+/// The Newton-Raphson method as a sequence of steps.
+///
+/// No bracket is kept, so the estimates may leave the area of the root.
+/// Steps end when rounding stops changing the estimate, or when the derivative is zero.
+///
+/// # Examples
+///
+/// ```
+/// use roots::NewtonRaphson;
+///
+/// let solver = NewtonRaphson::new(1f64, |x: f64| x * x - 2f64, |x: f64| 2f64 * x);
+/// // Residuals after each step
+/// let residuals: Vec<f64> = solver.take(5).map(|step| step.y.abs()).collect();
+/// assert!(residuals[4] < 1e-12f64);
+/// ```
+#[derive(Debug, Clone)]
+pub struct NewtonRaphson<F: FloatType, Func, Deriv> {
+    f: Func,
+    d: Deriv,
+    x: F,
+    fx: F,
+    /// The estimate before x
+    x_old: F,
+    /// The derivative at x is zero
+    zero_derivative: bool,
+    finished: bool,
+    /// Evaluations not reported by a step yet
+    evaluations: usize,
+}
+
+impl<F, Func, Deriv> NewtonRaphson<F, Func, Deriv>
+where
+    F: FloatType,
+    Func: FnMut(F) -> F,
+    Deriv: FnMut(F) -> F,
+{
+    /// Starts the search from the given estimate
+    pub fn new(start: F, mut f: Func, d: Deriv) -> Self {
+        let fx = f(start);
+        NewtonRaphson {
+            f,
+            d,
+            x: start,
+            fx,
+            x_old: start,
+            zero_derivative: false,
+            finished: false,
+            evaluations: 1,
+        }
+    }
+}
+
+impl<F, Func, Deriv> Iterator for NewtonRaphson<F, Func, Deriv>
+where
+    F: FloatType,
+    Func: FnMut(F) -> F,
+    Deriv: FnMut(F) -> F,
+{
+    type Item = Step<F>;
+
+    fn next(&mut self) -> Option<Step<F>> {
+        if self.finished {
+            return None;
+        }
+        if self.fx == F::zero() {
+            // The start point is a root
+            self.finished = true;
+            return Some(Step {
+                x: self.x,
+                y: F::zero(),
+                dx: F::zero(),
+                bracket: None,
+                evaluations: core::mem::take(&mut self.evaluations),
+                derivative_evaluations: 0,
+            });
+        }
+        let dfx = (self.d)(self.x);
+        if dfx == F::zero() {
+            self.zero_derivative = true;
+            self.finished = true;
+            return None;
+        }
+
+        let dx = self.fx / dfx;
+        let x = self.x - dx;
+        // Rounding makes the estimates alternate between neighbouring numbers
+        let cycled = x == self.x_old;
+        self.x_old = self.x;
+        self.x = x;
+        self.fx = (self.f)(x);
+        self.evaluations += 1;
+
+        self.finished = self.fx == F::zero() || dx == F::zero() || cycled;
+        Some(Step {
+            x: self.x,
+            y: self.fx,
+            dx: dx.abs(),
+            bracket: None,
+            evaluations: core::mem::take(&mut self.evaluations),
+            derivative_evaluations: 1,
+        })
+    }
+}
+
+/// Find a root of f(x)=0 by the Newton-Raphson method started from one estimate.
+///
+/// d is the derivative of f.
+/// The Convergency receives the value of f and the size of the last step.
+///
+/// # Failures
+/// ## ZeroDerivative
+/// The derivative is zero at an estimate.
+/// ## NoConvergency
+/// The algorithm cannot find a root within the given number of iterations.
+///
+/// # Examples
+///
+/// ```
+/// use roots::find_root_newton_raphson;
+///
+/// let f = |x: f64| x * x * x - 2f64;
+/// let d = |x: f64| 3f64 * x * x;
+/// let root = find_root_newton_raphson(1f64, f, d, &mut 1e-15f64);
+/// // Returns approximately Ok(1.2599210498948732)
+/// ```
+pub fn find_root_newton_raphson<F, Func, Deriv, Conv>(
+    start: F,
+    mut f: Func,
+    mut d: Deriv,
+    convergency: &mut Conv,
+) -> Result<F, SearchError>
+where
+    F: FloatType,
+    Func: FnMut(F) -> F,
+    Deriv: FnMut(F) -> F,
+    Conv: Convergency<F>,
+{
+    find_root_newton_raphson_fallible(start, |x| Ok(f(x)), |x| Ok(d(x)), convergency)
+}
+
+/// Find a root of f(x)=0 by the Newton-Raphson method started from one estimate, where f or its derivative may fail.
+///
+/// The search stops at the first error of f or d.
+///
+/// # Failures
+/// ## Function
+/// f or d has returned an error, which is passed on.
+/// ## ZeroDerivative
+/// The derivative is zero at an estimate.
+/// ## NoConvergency
+/// The algorithm cannot find a root within the given number of iterations.
+pub fn find_root_newton_raphson_fallible<F, E, Func, Deriv, Conv>(
+    start: F,
+    f: Func,
+    d: Deriv,
+    convergency: &mut Conv,
+) -> Result<F, SearchError<E>>
+where
+    F: FloatType,
+    Func: FnMut(F) -> Result<F, E>,
+    Deriv: FnMut(F) -> Result<F, E>,
+    Conv: Convergency<F>,
+{
+    let error = Cell::new(None);
+    let mut steps = NewtonRaphson::new(
+        start,
+        super::catching(f, &error, F::zero()),
+        super::catching(d, &error, F::zero()),
+    );
+    let result = match super::run(&mut steps, convergency, &error) {
+        Err(SearchError::Function(e)) => Err(SearchError::Function(e)),
+        _ if steps.zero_derivative => Err(SearchError::ZeroDerivative),
+        result => result,
+    };
+    convergency.on_result(result.as_ref().ok().copied());
+    result
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::super::*;
+
+    #[test]
+    fn test_newton_raphson_steps() {
+        let steps: Vec<Step<f64>> = NewtonRaphson::new(1f64, |x: f64| x * x * x - 2f64, |x: f64| 3f64 * x * x).collect();
+        assert!(steps.iter().all(|step| step.bracket.is_none()));
+        assert_float_eq!(1e-15, steps.last().unwrap().x, 2f64.cbrt());
+
+        let steps: Vec<Step<f64>> = NewtonRaphson::new(2f64, |x: f64| x - 2f64, |_| 1f64).collect();
+        assert_eq!(steps.len(), 1);
+        assert_eq!((steps[0].x, steps[0].y, steps[0].evaluations), (2f64, 0f64, 1));
+    }
+
+    #[test]
+    fn test_find_root_newton_raphson() {
+        let f = |x: f64| x * x * x - 2f64;
+        let d = |x: f64| 3f64 * x * x;
+        assert_float_eq!(1e-15, find_root_newton_raphson(1f64, f, d, &mut 1e-15f64).unwrap(), 2f64.cbrt());
+        assert_eq!(find_root_newton_raphson(0f64, f, d, &mut 1e-15f64), Err(SearchError::ZeroDerivative));
+        assert_eq!(
+            find_root_newton_raphson(1f64, f, d, &mut SimpleConvergency { eps: 0f64, max_iter: 2 }),
+            Err(SearchError::NoConvergency)
+        );
+
+        let f = |x: f64| if x < 1.5f64 { Ok(x * x * x - 2f64) } else { Err(x) };
+        let d = |x: f64| Ok(3f64 * x * x);
+        assert_float_eq!(1e-15, find_root_newton_raphson_fallible(1.4f64, f, d, &mut 1e-15f64).unwrap(), 2f64.cbrt());
+        assert_eq!(find_root_newton_raphson_fallible(0.5f64, f, d, &mut 1e-15f64), Err(SearchError::Function(3f64)));
+    }
+}
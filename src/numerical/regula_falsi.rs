@@ -0,0 +1,280 @@
+// Copyright (c) 2015, Mikhail Vorotilov
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without
+// modification, are permitted provided that the following conditions are met:
+//
+// * Redistributions of source code must retain the above copyright notice, this
+//   list of conditions and the following disclaimer.
+//
+// * Redistributions in binary form must reproduce the above copyright notice,
+//   this list of conditions and the following disclaimer in the documentation
+//   and/or other materials provided with the distribution.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+// AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+// IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+// FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+// DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+// CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+// OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+// OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use super::super::Convergency;
+use super::super::FloatType;
+use super::super::SearchError;
+use super::Step;
+
+use core::cell::Cell;
+
+/// The regula falsi method with the Illinois modification as a sequence of steps.
+///
+/// The end of the bracket kept twice in a row has its function value halved,
+/// so the bracket shrinks from both sides. If rounding puts the secant point at an end
+/// of the bracket, the bracket is bisected. Steps end when the bracket cannot be split any more.
+///
+/// # Examples
+///
+/// ```
+/// use roots::RegulaFalsi;
+///
+/// let mut solver = RegulaFalsi::new(0f64, 2f64, |x: f64| x * x * x - 2f64).unwrap();
+/// let first = solver.next().unwrap();
+/// assert_eq!(first.bracket, Some((0.5f64, 2f64)));
+/// // The search may be resumed later
+/// let fifth = solver.nth(3).unwrap();
+/// assert!(fifth.dx < first.dx);
+/// ```
+#[derive(Debug, Clone)]
+pub struct RegulaFalsi<F: FloatType, Func> {
+    f: Func,
+    a: F,
+    b: F,
+    fa: F,
+    fb: F,
+    /// The side of the bracket replaced at the previous iteration
+    side: i8,
+    /// A root found exactly at an end of the initial bracket
+    root: Option<F>,
+    finished: bool,
+    /// Evaluations not reported by a step yet
+    evaluations: usize,
+}
+
+impl<F, Func> RegulaFalsi<F, Func>
+where
+    F: FloatType,
+    Func: FnMut(F) -> F,
+{
+    /// Starts the search in the bracket [a, b]
+    ///
+    /// # Failures
+    /// ## NoBracketing
+    /// f(a) and f(b) have the same sign.
+    pub fn new(a: F, b: F, mut f: Func) -> Result<Self, SearchError> {
+        let (fa, fb) = (f(a), f(b));
+        let root = if fa == F::zero() {
+            Some(a)
+        } else if fb == F::zero() {
+            Some(b)
+        } else if (fa > F::zero()) == (fb > F::zero()) {
+            return Err(SearchError::NoBracketing);
+        } else {
+            None
+        };
+        Ok(RegulaFalsi {
+            f,
+            a,
+            b,
+            fa,
+            fb,
+            side: 0,
+            root,
+            finished: false,
+            evaluations: 2,
+        })
+    }
+}
+
+impl<F, Func> Iterator for RegulaFalsi<F, Func>
+where
+    F: FloatType,
+    Func: FnMut(F) -> F,
+{
+    type Item = Step<F>;
+
+    fn next(&mut self) -> Option<Step<F>> {
+        if self.finished {
+            return None;
+        }
+        if let Some(x) = self.root {
+            self.finished = true;
+            return Some(Step {
+                x,
+                y: F::zero(),
+                dx: F::zero(),
+                bracket: Some((x, x)),
+                evaluations: core::mem::take(&mut self.evaluations),
+                derivative_evaluations: 0,
+            });
+        }
+
+        let (low, high) = super::ordered(self.a, self.b);
+        let mut c = (self.a * self.fb - self.b * self.fa) / (self.fb - self.fa);
+        if !(low < c && c < high) {
+            // Rounding has put the secant point at an end; bisection still shrinks the bracket
+            c = (self.a + self.b) / F::two();
+        }
+        let fc = (self.f)(c);
+        self.evaluations += 1;
+        if (fc > F::zero()) == (self.fb > F::zero()) {
+            self.b = c;
+            self.fb = fc;
+            if self.side == -1 {
+                self.fa = self.fa / F::two();
+            }
+            self.side = -1;
+        } else {
+            self.a = c;
+            self.fa = fc;
+            if self.side == 1 {
+                self.fb = self.fb / F::two();
+            }
+            self.side = 1;
+        }
+
+        // Stop if the bracket cannot be split any more
+        let middle = (self.a + self.b) / F::two();
+        self.finished = fc == F::zero() || middle == self.a || middle == self.b;
+        Some(Step {
+            x: c,
+            y: fc,
+            dx: (self.b - self.a).abs(),
+            bracket: Some(super::ordered(self.a, self.b)),
+            evaluations: core::mem::take(&mut self.evaluations),
+            derivative_evaluations: 0,
+        })
+    }
+}
+
+/// Find a root of f(x)=0 in the bracket [a, b] by the regula falsi method with the Illinois modification.
+///
+/// The Convergency receives the value of f and the width of the bracket.
+///
+/// # Failures
+/// ## NoBracketing
+/// f(a) and f(b) have the same sign.
+/// ## NoConvergency
+/// The algorithm cannot find a root within the given number of iterations.
+///
+/// # Examples
+///
+/// ```
+/// use roots::find_root_regula_falsi;
+///
+/// let f = |x: f64| x * x * x - 2f64;
+/// let root = find_root_regula_falsi(0f64, 2f64, f, &mut 1e-15f64);
+/// // Returns approximately Ok(1.2599210498948732)
+/// ```
+pub fn find_root_regula_falsi<F, Func, Conv>(a: F, b: F, mut f: Func, convergency: &mut Conv) -> Result<F, SearchError>
+where
+    F: FloatType,
+    Func: FnMut(F) -> F,
+    Conv: Convergency<F>,
+{
+    find_root_regula_falsi_fallible(a, b, |x| Ok(f(x)), convergency)
+}
+
+/// Find a root of f(x)=0 in the bracket [a, b] by the regula falsi method, where f may fail.
+///
+/// The search stops at the first error of f.
+///
+/// # Failures
+/// ## Function
+/// f has returned an error, which is passed on.
+/// ## NoBracketing
+/// f(a) and f(b) have the same sign.
+/// ## NoConvergency
+/// The algorithm cannot find a root within the given number of iterations.
+pub fn find_root_regula_falsi_fallible<F, E, Func, Conv>(a: F, b: F, f: Func, convergency: &mut Conv) -> Result<F, SearchError<E>>
+where
+    F: FloatType,
+    Func: FnMut(F) -> Result<F, E>,
+    Conv: Convergency<F>,
+{
+    let error = Cell::new(None);
+    let solver = RegulaFalsi::new(a, b, super::catching(f, &error, F::zero()));
+    super::solve(solver, convergency, &error)
+}
+
+/// Find a root of f(x)=0 near x0 by the regula falsi method in a bracket found by find_bracket.
+///
+/// # Failures
+/// ## NoBracketing
+/// No sign change is found around x0 within the given number of iterations.
+/// ## NoConvergency
+/// The algorithm cannot find a root within the given number of iterations.
+pub fn find_root_regula_falsi_from<F, Func, Conv>(x0: F, mut f: Func, convergency: &mut Conv) -> Result<F, SearchError>
+where
+    F: FloatType,
+    Func: FnMut(F) -> F,
+    Conv: Convergency<F>,
+{
+    let (a, b) = super::bracket::find_bracket(&mut f, x0, super::bracket::initial_step(x0), convergency)?;
+    find_root_regula_falsi(a, b, f, convergency)
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::super::*;
+
+    #[test]
+    fn test_regula_falsi_steps() {
+        let steps: Vec<Step<f64>> = RegulaFalsi::new(0f64, 2f64, |x: f64| x * x * x - 2f64).unwrap().collect();
+        // The bracket shrinks around the root until it cannot be split any more
+        for pair in steps.windows(2) {
+            let (a0, b0) = pair[0].bracket.unwrap();
+            let (a1, b1) = pair[1].bracket.unwrap();
+            assert!(a0 <= a1 && b1 <= b0);
+        }
+        assert_float_eq!(1e-15, steps.last().unwrap().x, 2f64.cbrt());
+
+        let steps: Vec<Step<f64>> = RegulaFalsi::new(1f64, 2f64, |x: f64| x - 2f64).unwrap().collect();
+        assert_eq!(steps.len(), 1);
+        assert_eq!((steps[0].x, steps[0].y, steps[0].bracket), (2f64, 0f64, Some((2f64, 2f64))));
+        assert!(RegulaFalsi::new(2f64, 3f64, |x: f64| x - 1f64).is_err());
+    }
+
+    #[test]
+    fn test_find_root_regula_falsi() {
+        let f = |x: f64| x * x * x - 2f64;
+        assert_float_eq!(1e-15, find_root_regula_falsi(0f64, 2f64, f, &mut 1e-15f64).unwrap(), 2f64.cbrt());
+        assert_eq!(find_root_regula_falsi(1f64, 2f64, |x: f64| x - 1f64, &mut 1e-15f64), Ok(1f64));
+        assert_eq!(find_root_regula_falsi(2f64, 3f64, f, &mut 1e-15f64), Err(SearchError::NoBracketing));
+        assert_eq!(
+            find_root_regula_falsi(0f64, 2f64, f, &mut SimpleConvergency { eps: 0f64, max_iter: 3 }),
+            Err(SearchError::NoConvergency)
+        );
+
+        assert_float_eq!(1e-15, find_root_regula_falsi_from(-50f64, f, &mut 1e-15f64).unwrap(), 2f64.cbrt());
+
+        // Values at the ends of the last brackets are rounding errors, the secant point is an end
+        let p = [
+            -0.45148731520991525f64,
+            5.224892286790029f64,
+            7.846097494125757f64,
+            2.4858490952213996f64,
+            -3.9563076685020526f64,
+            1.6496655279079109f64,
+        ];
+        let mut convergency = SimpleConvergency { eps: 1e-15f64, max_iter: 100 };
+        let root = find_root_regula_falsi(12.9375f64, 12.953125f64, |x| polynomial::eval(&p, x), &mut convergency).unwrap();
+        assert_float_eq!(1e-14, root, 12.944137559206977f64);
+
+        let f = |x: f64| if x < 1.5f64 { Ok(x * x * x - 2f64) } else { Err(x) };
+        assert_float_eq!(1e-15, find_root_regula_falsi_fallible(0f64, 1.4f64, f, &mut 1e-15f64).unwrap(), 2f64.cbrt());
+        assert_eq!(find_root_regula_falsi_fallible(0f64, 2f64, f, &mut 1e-15f64), Err(SearchError::Function(2f64)));
+    }
+}
@@ -0,0 +1,234 @@
+// Copyright (c) 2015, Mikhail Vorotilov
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without
+// modification, are permitted provided that the following conditions are met:
+//
+// * Redistributions of source code must retain the above copyright notice, this
+//   list of conditions and the following disclaimer.
+//
+// * Redistributions in binary form must reproduce the above copyright notice,
+//   this list of conditions and the following disclaimer in the documentation
+//   and/or other materials provided with the distribution.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+// AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+// IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+// FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+// DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+// CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+// OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+// OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+use super::super::Convergency;
+use super::super::FloatType;
+use super::super::SearchError;
+use super::Step;
+
+use core::cell::Cell;
+
+/// The secant method as a sequence of steps.
+///
+/// Each step replaces the older of two estimates by the root of the line through them.
+/// No bracket is kept, so the estimates may leave the area of the root.
+/// Steps end when rounding stops changing the estimates, or when the line through
+/// the two estimates is horizontal.
+///
+/// # Examples
+///
+/// ```
+/// use roots::Secant;
+///
+/// let solver = Secant::new(1f64, 2f64, |x: f64| x * x - 2f64);
+/// // Sizes of the steps shrink superlinearly
+/// let steps: Vec<f64> = solver.take(6).map(|step| step.dx).collect();
+/// assert!(steps[5] < 1e-9f64);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Secant<F: FloatType, Func> {
+    f: Func,
+    /// The older estimate
+    x0: F,
+    /// The newer estimate
+    x1: F,
+    f0: F,
+    f1: F,
+    /// The line through the estimates is horizontal
+    zero_derivative: bool,
+    finished: bool,
+    /// Evaluations not reported by a step yet
+    evaluations: usize,
+}
+
+impl<F, Func> Secant<F, Func>
+where
+    F: FloatType,
+    Func: FnMut(F) -> F,
+{
+    /// Starts the search from two estimates
+    pub fn new(first: F, second: F, mut f: Func) -> Self {
+        let (f_first, f_second) = (f(first), f(second));
+        // An exact root is kept as the newer estimate
+        let (x0, f0, x1, f1) = if f_first == F::zero() {
+            (second, f_second, first, f_first)
+        } else {
+            (first, f_first, second, f_second)
+        };
+        Secant {
+            f,
+            x0,
+            x1,
+            f0,
+            f1,
+            zero_derivative: false,
+            finished: false,
+            evaluations: 2,
+        }
+    }
+}
+
+impl<F, Func> Iterator for Secant<F, Func>
+where
+    F: FloatType,
+    Func: FnMut(F) -> F,
+{
+    type Item = Step<F>;
+
+    fn next(&mut self) -> Option<Step<F>> {
+        if self.finished {
+            return None;
+        }
+        if self.f1 == F::zero() {
+            self.finished = true;
+            return Some(Step {
+                x: self.x1,
+                y: F::zero(),
+                dx: F::zero(),
+                bracket: None,
+                evaluations: core::mem::take(&mut self.evaluations),
+                derivative_evaluations: 0,
+            });
+        }
+        if self.f1 == self.f0 {
+            self.zero_derivative = true;
+            self.finished = true;
+            return None;
+        }
+
+        let x2 = self.x1 - self.f1 * (self.x1 - self.x0) / (self.f1 - self.f0);
+        let f2 = (self.f)(x2);
+        self.evaluations += 1;
+        let dx = (x2 - self.x1).abs();
+        // Rounding makes the estimates alternate between neighbouring numbers
+        let cycled = x2 == self.x0;
+        self.x0 = self.x1;
+        self.f0 = self.f1;
+        self.x1 = x2;
+        self.f1 = f2;
+
+        self.finished = f2 == F::zero() || dx == F::zero() || cycled;
+        Some(Step {
+            x: x2,
+            y: f2,
+            dx,
+            bracket: None,
+            evaluations: core::mem::take(&mut self.evaluations),
+            derivative_evaluations: 0,
+        })
+    }
+}
+
+/// Find a root of f(x)=0 by the secant method started from two estimates.
+///
+/// The Convergency receives the value of f and the size of the last step.
+///
+/// # Failures
+/// ## ZeroDerivative
+/// The line through two consecutive estimates is horizontal.
+/// ## NoConvergency
+/// The algorithm cannot find a root within the given number of iterations.
+///
+/// # Examples
+///
+/// ```
+/// use roots::find_root_secant;
+///
+/// let f = |x: f64| x * x * x - 2f64;
+/// let root = find_root_secant(1f64, 2f64, f, &mut 1e-15f64);
+/// // Returns approximately Ok(1.2599210498948732)
+/// ```
+pub fn find_root_secant<F, Func, Conv>(first: F, second: F, mut f: Func, convergency: &mut Conv) -> Result<F, SearchError>
+where
+    F: FloatType,
+    Func: FnMut(F) -> F,
+    Conv: Convergency<F>,
+{
+    find_root_secant_fallible(first, second, |x| Ok(f(x)), convergency)
+}
+
+/// Find a root of f(x)=0 by the secant method started from two estimates, where f may fail.
+///
+/// The search stops at the first error of f.
+///
+/// # Failures
+/// ## Function
+/// f has returned an error, which is passed on.
+/// ## ZeroDerivative
+/// The line through two consecutive estimates is horizontal.
+/// ## NoConvergency
+/// The algorithm cannot find a root within the given number of iterations.
+pub fn find_root_secant_fallible<F, E, Func, Conv>(
+    first: F,
+    second: F,
+    f: Func,
+    convergency: &mut Conv,
+) -> Result<F, SearchError<E>>
+where
+    F: FloatType,
+    Func: FnMut(F) -> Result<F, E>,
+    Conv: Convergency<F>,
+{
+    let error = Cell::new(None);
+    let mut steps = Secant::new(first, second, super::catching(f, &error, F::zero()));
+    let result = match super::run(&mut steps, convergency, &error) {
+        Err(SearchError::Function(e)) => Err(SearchError::Function(e)),
+        _ if steps.zero_derivative => Err(SearchError::ZeroDerivative),
+        result => result,
+    };
+    convergency.on_result(result.as_ref().ok().copied());
+    result
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::super::*;
+
+    #[test]
+    fn test_secant_steps() {
+        let steps: Vec<Step<f64>> = Secant::new(1f64, 2f64, |x: f64| x * x * x - 2f64).collect();
+        assert!(steps.iter().all(|step| step.bracket.is_none()));
+        assert_float_eq!(1e-15, steps.last().unwrap().x, 2f64.cbrt());
+
+        let steps: Vec<Step<f64>> = Secant::new(1f64, 2f64, |x: f64| x - 1f64).collect();
+        assert_eq!(steps.len(), 1);
+        assert_eq!((steps[0].x, steps[0].y, steps[0].evaluations), (1f64, 0f64, 2));
+    }
+
+    #[test]
+    fn test_find_root_secant() {
+        let f = |x: f64| x * x * x - 2f64;
+        assert_float_eq!(1e-15, find_root_secant(1f64, 2f64, f, &mut 1e-15f64).unwrap(), 2f64.cbrt());
+        assert_float_eq!(1e-15, find_root_secant(2f64, 3f64, f, &mut 1e-15f64).unwrap(), 2f64.cbrt());
+        assert_eq!(find_root_secant(1f64, 2f64, |x: f64| x - 2f64, &mut 1e-15f64), Ok(2f64));
+        assert_eq!(find_root_secant(-1f64, 1f64, |x: f64| x * x + 1f64, &mut 1e-15f64), Err(SearchError::ZeroDerivative));
+        assert_eq!(
+            find_root_secant(1f64, 2f64, f, &mut SimpleConvergency { eps: 0f64, max_iter: 3 }),
+            Err(SearchError::NoConvergency)
+        );
+
+        let f = |x: f64| if x < 1.5f64 { Ok(x * x * x - 2f64) } else { Err(x) };
+        assert_float_eq!(1e-15, find_root_secant_fallible(1f64, 1.4f64, f, &mut 1e-15f64).unwrap(), 2f64.cbrt());
+        assert_eq!(find_root_secant_fallible(1f64, 2f64, f, &mut 1e-15f64), Err(SearchError::Function(2f64)));
+    }
+}
@@ -0,0 +1,299 @@
+// Copyright (c) 2015, Mikhail Vorotilov
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without
+// modification, are permitted provided that the following conditions are met:
+//
+// * Redistributions of source code must retain the above copyright notice, this
+//   list of conditions and the following disclaimer.
+//
+// * Redistributions in binary form must reproduce the above copyright notice,
+//   this list of conditions and the following disclaimer in the documentation
+//   and/or other materials provided with the distribution.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+// AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+// IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+// FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+// DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+// CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+// OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+// OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use super::super::Convergency;
+use super::super::FloatType;
+use super::super::SearchError;
+use super::super::SimpleConvergency;
+use super::regula_falsi::find_root_regula_falsi;
+
+use core::cell::Cell;
+
+/// Parameters of find_all_roots_in
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SubdivisionOptions<F: FloatType> {
+    /// Number of equal segments the interval is split into, at most 32767.
+    /// Roots closer to each other than a segment may be missed.
+    pub segments: usize,
+    /// The search stops after finding this number of roots
+    pub max_roots: usize,
+    /// The search stops after this number of evaluations of the function
+    pub max_evaluations: usize,
+    /// Refinement of each root; a minimum of |f| is a root if is_root_found accepts its value
+    pub convergency: SimpleConvergency<F>,
+}
+
+impl<F: FloatType> Default for SubdivisionOptions<F> {
+    fn default() -> Self {
+        SubdivisionOptions {
+            segments: 100,
+            max_roots: 100,
+            max_evaluations: 100000,
+            convergency: SimpleConvergency {
+                eps: F::epsilon(),
+                max_iter: 100,
+            },
+        }
+    }
+}
+
+/// Finds the minimum of |f| in [a, b] by the golden section search.
+///
+/// Returns Err with a point where f has the sign opposite to sign_positive, if one is met.
+fn minimize_abs<F, Func>(a: F, b: F, sign_positive: bool, f: &mut Func, convergency: &mut SimpleConvergency<F>) -> Result<F, F>
+where
+    F: FloatType,
+    Func: FnMut(F) -> F,
+{
+    let ratio = (F::five().sqrt() - F::one()) / F::two();
+    let (mut a, mut b) = (a, b);
+    let mut x1 = b - ratio * (b - a);
+    let mut x2 = a + ratio * (b - a);
+    let (mut f1, mut f2) = (f(x1), f(x2));
+    let mut iter = 0;
+    loop {
+        for &(x, y) in [(x1, f1), (x2, f2)].iter() {
+            if y != F::zero() && (y > F::zero()) != sign_positive {
+                return Err(x);
+            }
+        }
+        if f1.abs() < f2.abs() {
+            b = x2;
+            x2 = x1;
+            f2 = f1;
+            x1 = b - ratio * (b - a);
+            f1 = f(x1);
+        } else {
+            a = x1;
+            x1 = x2;
+            f1 = f2;
+            x2 = a + ratio * (b - a);
+            f2 = f(x2);
+        }
+        iter += 1;
+        if convergency.is_converged(b - a) || convergency.is_iteration_limit_reached(iter) || x1 >= x2 {
+            return Ok(if f1.abs() < f2.abs() { x1 } else { x2 });
+        }
+    }
+}
+
+/// Adds a root, unless it is closer to one of the found roots than the convergency tolerance
+fn add_root<F: FloatType>(found: &mut Vec<F>, x: F, tolerance: &mut SimpleConvergency<F>) {
+    if !found.iter().any(|&root| tolerance.is_converged(x - root)) {
+        found.push(x);
+    }
+}
+
+/// Finds all roots of f in [a, b] by subdivision.
+///
+/// The interval is split into equal segments. A segment where f changes its sign is refined
+/// by the regula falsi method. Roots without a sign change (touching the axis) are looked for
+/// at local minima of |f| on the grid: |f| is minimized by the golden section search around them,
+/// and the minimum is a root if the convergency accepts its value. Roots are ordered;
+/// roots closer than the convergency tolerance are returned once.
+/// The search stops when options.max_roots roots are found or f has been evaluated
+/// options.max_evaluations times, returning the roots found so far. The grid and the refinements
+/// are charged against options.max_evaluations, so f is never evaluated more often.
+///
+/// # Failures
+/// ## NoConvergency
+/// A root cannot be refined within the given number of iterations.
+///
+/// # Examples
+///
+/// ```
+/// use roots::find_all_roots_in;
+/// use roots::SubdivisionOptions;
+///
+/// // sin(x) has 7 roots in [-10, 10]
+/// let roots = find_all_roots_in(-10f64, 10f64, |x: f64| x.sin(), &SubdivisionOptions::default()).unwrap();
+/// assert_eq!(roots.len(), 7);
+/// ```
+pub fn find_all_roots_in<F, Func>(a: F, b: F, mut f: Func, options: &SubdivisionOptions<F>) -> Result<Vec<F>, SearchError>
+where
+    F: FloatType,
+    Func: FnMut(F) -> F,
+{
+    let evaluations = Cell::new(0usize);
+    let mut f = |x: F| {
+        evaluations.set(evaluations.get() + 1);
+        f(x)
+    };
+    let out_of_budget = || evaluations.get() >= options.max_evaluations;
+    // Convergency of one refinement, with iterations capped by the evaluations left in the budget:
+    // both methods evaluate two points before the first iteration, one per iteration, and one more at the end.
+    // None if no iteration is left
+    let refinement = || match options.max_evaluations.saturating_sub(evaluations.get() + 3) {
+        0 => None,
+        left => Some(SimpleConvergency {
+            max_iter: options.convergency.max_iter.min(left),
+            ..options.convergency
+        }),
+    };
+    // Whether the cap of the budget, rather than the convergency itself, has stopped a refinement
+    let capped = |convergency: &SimpleConvergency<F>| convergency.max_iter < options.convergency.max_iter;
+
+    let n = options.segments.clamp(1, i16::MAX as usize);
+    let mut grid: Vec<(F, F)> = Vec::with_capacity(n + 1);
+    for i in 0..=n {
+        if out_of_budget() {
+            break;
+        }
+        let x = if i == n {
+            b
+        } else {
+            a + (b - a) * F::from(i as i16) / F::from(n as i16)
+        };
+        grid.push((x, f(x)));
+    }
+
+    let mut found = Vec::new();
+    let mut tolerance = options.convergency;
+    for i in 0..grid.len().saturating_sub(1) {
+        if found.len() >= options.max_roots {
+            break;
+        }
+        let (x0, y0) = grid[i];
+        let (x1, y1) = grid[i + 1];
+        if y0 == F::zero() {
+            add_root(&mut found, x0, &mut tolerance);
+            continue;
+        }
+        // Without evaluations left, only roots at the points of the grid are found
+        let mut convergency = match refinement() {
+            Some(convergency) => convergency,
+            None => continue,
+        };
+        if y1 != F::zero() && (y0 > F::zero()) != (y1 > F::zero()) {
+            match find_root_regula_falsi(x0, x1, &mut f, &mut convergency) {
+                Ok(x) => add_root(&mut found, x, &mut tolerance),
+                Err(SearchError::NoConvergency) if capped(&convergency) => break,
+                Err(error) => return Err(error),
+            }
+        } else if i > 0 && y1 != F::zero() {
+            // A local minimum of |f| at x0 with the same sign around it
+            let (x_left, y_left) = grid[i - 1];
+            if (y_left > F::zero()) == (y0 > F::zero()) && y0.abs() <= y_left.abs() && y0.abs() <= y1.abs() {
+                match minimize_abs(x_left, x1, y0 > F::zero(), &mut f, &mut convergency) {
+                    Ok(x) => {
+                        if convergency.is_root_found(f(x)) {
+                            add_root(&mut found, x, &mut tolerance);
+                        }
+                    }
+                    Err(x) => {
+                        // Two roots close to each other
+                        for &(left, right) in [(x_left, x), (x, x1)].iter() {
+                            let mut convergency = match refinement() {
+                                Some(convergency) => convergency,
+                                None => break,
+                            };
+                            match find_root_regula_falsi(left, right, &mut f, &mut convergency) {
+                                Ok(x) => add_root(&mut found, x, &mut tolerance),
+                                Err(SearchError::NoConvergency) if capped(&convergency) => break,
+                                Err(error) => return Err(error),
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+    if let Some(&(x, y)) = grid.last() {
+        if y == F::zero() {
+            add_root(&mut found, x, &mut tolerance);
+        }
+    }
+
+    found.sort_by(|x, y| x.partial_cmp(y).unwrap_or(core::cmp::Ordering::Equal));
+    found.truncate(options.max_roots);
+    Ok(found)
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::super::*;
+
+    #[test]
+    fn test_find_all_roots_in() {
+        let options = SubdivisionOptions::default();
+        let roots = find_all_roots_in(-10f64, 10f64, |x: f64| x.sin(), &options).unwrap();
+        let expected: Vec<f64> = (-3..=3).map(|k| k as f64 * core::f64::consts::PI).collect();
+        assert_float_array_eq!(1e-14, roots.into_iter(), expected);
+
+        // Grid points are exact roots
+        let roots = find_all_roots_in(0f64, 4f64, |x: f64| (x - 1f64) * (x - 2f64) * (x - 4f64), &options).unwrap();
+        assert_eq!(roots, vec![1f64, 2f64, 4f64]);
+        assert_eq!(find_all_roots_in(0f64, 4f64, |x: f64| x * x + 1f64, &options).unwrap(), vec![]);
+    }
+
+    #[test]
+    fn test_find_all_roots_in_tangency() {
+        // The double root at 0.3 does not change the sign
+        let options = SubdivisionOptions {
+            convergency: SimpleConvergency { eps: 1e-12f64, max_iter: 100 },
+            ..SubdivisionOptions::default()
+        };
+        let f = |x: f64| (x - 0.3f64) * (x - 0.3f64) * (x + 2f64);
+        let roots = find_all_roots_in(-3f64, 3f64, f, &options).unwrap();
+        assert_float_array_eq!(1e-6, roots.into_iter(), [-2f64, 0.3f64]);
+
+        // Two roots inside one pair of segments are split by the minimization
+        let options = SubdivisionOptions { segments: 4, ..options };
+        let f = |x: f64| (x - 0.1f64) * (x - 0.2f64);
+        let roots = find_all_roots_in(-1f64, 1f64, f, &options).unwrap();
+        assert_float_array_eq!(1e-12, roots.into_iter(), [0.1f64, 0.2f64]);
+    }
+
+    #[test]
+    fn test_find_all_roots_in_limits() {
+        let options = SubdivisionOptions { max_roots: 3, ..SubdivisionOptions::default() };
+        let roots = find_all_roots_in(0.5f64, 20f64, |x: f64| x.sin(), &options).unwrap();
+        assert_eq!(roots.len(), 3);
+
+        // The grid takes 101 evaluations, leaving a few for the refinement
+        let options = SubdivisionOptions { max_evaluations: 110, ..SubdivisionOptions::default() };
+        let roots = find_all_roots_in(0.5f64, 20f64, |x: f64| x.sin(), &options).unwrap();
+        assert!(!roots.is_empty() && roots.len() < 6);
+
+        // The grid and the refinements are charged against the budget
+        for &max_evaluations in [20usize, 50, 110, 120].iter() {
+            let mut evaluations = 0;
+            let options = SubdivisionOptions { max_evaluations, ..SubdivisionOptions::default() };
+            let f = |x: f64| {
+                evaluations += 1;
+                x.sin()
+            };
+            let roots = find_all_roots_in(0.5f64, 20f64, f, &options).unwrap();
+            assert!(evaluations <= max_evaluations);
+            assert!(roots.iter().all(|&x| (x / core::f64::consts::PI - (x / core::f64::consts::PI).round()).abs() < 1e-12));
+        }
+
+        // Roots at -1e-20 and 1e-20 are counted once, and do not crowd out the root at 0.75
+        let options = SubdivisionOptions { max_roots: 2, segments: 4, ..SubdivisionOptions::default() };
+        let roots = find_all_roots_in(-1f64, 1f64, |x: f64| (x * x - 1e-40f64) * (x - 0.75f64), &options).unwrap();
+        assert_eq!(roots.len(), 2);
+        assert!(roots[0].abs() < 1e-15);
+        assert_float_eq!(1e-15, roots[1], 0.75f64);
+    }
+}
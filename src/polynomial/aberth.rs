@@ -0,0 +1,233 @@
+// Copyright (c) 2015, Mikhail Vorotilov
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without
+// modification, are permitted provided that the following conditions are met:
+//
+// * Redistributions of source code must retain the above copyright notice, this
+//   list of conditions and the following disclaimer.
+//
+// * Redistributions in binary form must reproduce the above copyright notice,
+//   this list of conditions and the following disclaimer in the documentation
+//   and/or other materials provided with the distribution.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+// AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+// IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+// FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+// DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+// CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+// OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+// OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use super::super::Complex;
+use super::super::Convergency;
+use super::super::FloatType;
+use super::super::SearchError;
+
+use core::cmp::Ordering;
+
+/// Approximation of a complex root with an a-posteriori error bound
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ComplexRoot<F: FloatType> {
+    /// The approximation
+    pub root: Complex<F>,
+    /// Radius of the disc around the approximation containing a root.
+    /// If k discs overlap, their union contains k roots.
+    pub radius: F,
+}
+
+/// Initial approximations on circles with radii given by the upper convex hull
+/// of the points (i, ln|a_i|), the Newton polygon. Coefficients start from the lowest degree;
+/// a_0 and a_n are not zero.
+fn newton_polygon_guesses<F: FloatType>(a: &[F]) -> Vec<Complex<F>> {
+    let n = a.len() - 1;
+
+    // Upper hull by the monotone chain
+    let mut hull: Vec<(usize, F)> = Vec::with_capacity(a.len());
+    for (i, &a_i) in a.iter().enumerate().filter(|&(_, &a_i)| a_i != F::zero()) {
+        let point = (i, a_i.abs().ln());
+        while hull.len() >= 2 {
+            let (o, m) = (hull[hull.len() - 2], hull[hull.len() - 1]);
+            let cross = F::from((m.0 - o.0) as i16) * (point.1 - o.1) - (m.1 - o.1) * F::from((point.0 - o.0) as i16);
+            if cross >= F::zero() {
+                // m is not above the segment from o to the point
+                hull.pop();
+            } else {
+                break;
+            }
+        }
+        hull.push(point);
+    }
+
+    let two_pi = F::two() * F::pi();
+    let sigma = F::from(7i16) / F::from(10i16);
+    let mut guesses = Vec::with_capacity(n);
+    for edge in hull.windows(2) {
+        let (k_left, k_right) = (edge[0].0, edge[1].0);
+        let m = F::from((k_right - k_left) as i16);
+        let radius = (a[k_left].abs() / a[k_right].abs()).powf(F::one() / m);
+        for t in 0..k_right - k_left {
+            let angle = two_pi * F::from(t as i16) / m + two_pi * F::from(k_left as i16) / F::from(n as i16) + sigma;
+            guesses.push(Complex::new(radius * angle.cos(), radius * angle.sin()));
+        }
+    }
+    guesses
+}
+
+/// Finds all complex roots of a polynomial by the Aberth-Ehrlich simultaneous iteration.
+///
+/// Coefficients start from the highest degree. Initial approximations are placed on circles
+/// given by the Newton polygon of the coefficients. Each approximation stops moving once
+/// the Convergency accepts |p(z)| or the size of its last correction, or once p(z) vanishes
+/// within rounding errors. Every returned root has a radius of a disc guaranteed to contain
+/// a root of the polynomial. Roots are ordered by the real part, then by the imaginary one.
+///
+/// # Failures
+/// ## NoConvergency
+/// Some approximations have not converged within the given number of iterations.
+///
+/// # Examples
+///
+/// ```
+/// use roots::find_roots_aberth;
+/// use roots::SimpleConvergency;
+///
+/// // x^4 + 1 = 0 has roots (±1 ± i) / sqrt(2)
+/// let mut convergency = SimpleConvergency { eps: 1e-15f64, max_iter: 100 };
+/// let roots = find_roots_aberth(&[1f64, 0f64, 0f64, 0f64, 1f64], &mut convergency).unwrap();
+/// assert_eq!(roots.len(), 4);
+/// for root in roots {
+///     assert!(root.radius < 1e-14f64);
+/// }
+/// ```
+pub fn find_roots_aberth<F, C>(coefficients: &[F], convergency: &mut C) -> Result<Vec<ComplexRoot<F>>, SearchError>
+where
+    F: FloatType,
+    C: Convergency<F>,
+{
+    let a = super::trim(coefficients);
+    if a.len() < 2 {
+        return Ok(Vec::new());
+    }
+
+    // Zero roots are known exactly
+    let zero_roots = a.iter().rev().take_while(|&&x| x == F::zero()).count();
+    let a = &a[..a.len() - zero_roots];
+    let n = a.len() - 1;
+
+    let lowest_first: Vec<F> = a.iter().rev().cloned().collect();
+    let mut z = if n > 0 { newton_polygon_guesses(&lowest_first) } else { Vec::new() };
+    let mut converged = vec![false; n];
+    let mut values = [Complex::zero(); 2];
+    let mut iter = 0;
+    while converged.iter().any(|&c| !c) {
+        for i in 0..n {
+            if converged[i] {
+                continue;
+            }
+            super::eval_complex_derivatives(a, z[i], &mut values);
+            let (p, dp) = (values[0], values[1]);
+            let p_abs = p.abs();
+            if p_abs <= super::rounding_error(a.iter().map(|a_k| a_k.abs()), z[i].abs()) || convergency.is_root_found(p_abs) {
+                converged[i] = true;
+                continue;
+            }
+
+            // Newton correction p/p' adjusted by the other approximations
+            let sum = z
+                .iter()
+                .enumerate()
+                .filter(|&(j, _)| j != i)
+                .fold(Complex::zero(), |acc, (_, &z_j)| acc + Complex::one() / (z[i] - z_j));
+            let correction = Complex::one() / (dp / p - sum);
+            z[i] = z[i] - correction;
+
+            let correction_abs = correction.abs();
+            if convergency.is_converged(correction_abs) || correction_abs <= F::epsilon() * z[i].abs() {
+                converged[i] = true;
+            }
+        }
+
+        iter += 1;
+        if convergency.is_iteration_limit_reached(iter) {
+            return Err(SearchError::NoConvergency);
+        }
+    }
+
+    // Error radii by the Weierstrass corrections: n * |p(z_i)| / |a_n * prod(z_i - z_j)|
+    let mut roots: Vec<ComplexRoot<F>> = (0..n)
+        .map(|i| {
+            super::eval_complex_derivatives(a, z[i], &mut values[..1]);
+            let p_abs = values[0].abs() + super::rounding_error(a.iter().map(|a_k| a_k.abs()), z[i].abs());
+            let product = z
+                .iter()
+                .enumerate()
+                .filter(|&(j, _)| j != i)
+                .fold(a[0].abs(), |acc, (_, &z_j)| acc * (z[i] - z_j).abs());
+            ComplexRoot {
+                root: z[i],
+                radius: F::from(n as i16) * p_abs / product,
+            }
+        })
+        .collect();
+    roots.extend((0..zero_roots).map(|_| ComplexRoot {
+        root: Complex::zero(),
+        radius: F::zero(),
+    }));
+    roots.sort_by(|x, y| {
+        (x.root.re, x.root.im)
+            .partial_cmp(&(y.root.re, y.root.im))
+            .unwrap_or(Ordering::Equal)
+    });
+    Ok(roots)
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::super::*;
+
+    #[test]
+    fn test_find_roots_aberth() {
+        let mut convergency = SimpleConvergency { eps: 1e-15f64, max_iter: 100 };
+        assert!(find_roots_aberth(&[2f64], &mut convergency).unwrap().is_empty());
+
+        // (x - 1)(x - 2)(x^2 + 4) x
+        let roots = find_roots_aberth(&[1f64, -3f64, 6f64, -12f64, 8f64, 0f64], &mut convergency).unwrap();
+        let expected = [
+            Complex::new(0f64, 0f64),
+            Complex::new(0f64, -2f64),
+            Complex::new(0f64, 2f64),
+            Complex::new(1f64, 0f64),
+            Complex::new(2f64, 0f64),
+        ];
+        assert_eq!(roots.len(), expected.len());
+        for &e in expected.iter() {
+            // Real parts of the conjugate pair may differ by rounding, so the order is not checked
+            assert!(roots.iter().any(|root| (root.root - e).abs() <= root.radius && root.radius < 1e-12f64));
+        }
+    }
+
+    #[test]
+    fn test_find_roots_aberth_high_degree() {
+        // x^100 - 1 has roots on the unit circle
+        let mut coefficients = vec![0f64; 101];
+        coefficients[0] = 1f64;
+        coefficients[100] = -1f64;
+        let mut convergency = SimpleConvergency { eps: 1e-15f64, max_iter: 200 };
+        let roots = find_roots_aberth(&coefficients, &mut convergency).unwrap();
+        assert_eq!(roots.len(), 100);
+        for root in roots {
+            assert_float_eq!(1e-13, root.root.abs(), 1f64);
+            assert!(root.radius < 1e-11f64);
+        }
+
+        // Coefficients of very different magnitudes: (x - 1e-5)(x - 1)(x - 1e5)
+        let roots = find_roots_aberth(&[1f64, -100001.00001f64, 100001.00001f64, -1f64], &mut convergency).unwrap();
+        assert_float_eq!(1e-20, roots[0].root.re, 1e-5f64);
+        assert_float_eq!(1e-14, roots[1].root.re, 1f64);
+        assert_float_eq!(1e-10, roots[2].root.re, 1e5f64);
+    }
+}
@@ -0,0 +1,441 @@
+// Copyright (c) 2015, Mikhail Vorotilov
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without
+// modification, are permitted provided that the following conditions are met:
+//
+// * Redistributions of source code must retain the above copyright notice, this
+//   list of conditions and the following disclaimer.
+//
+// * Redistributions in binary form must reproduce the above copyright notice,
+//   this list of conditions and the following disclaimer in the documentation
+//   and/or other materials provided with the distribution.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+// AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+// IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+// FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+// DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+// CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+// OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+// OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use super::super::Convergency;
+use super::super::FloatType;
+use super::super::SearchError;
+use super::RootsVec;
+
+/// Binomial coefficient C(n, k) as a float
+fn binomial<F: FloatType>(n: usize, k: usize) -> F {
+    let k = k.min(n - k);
+    (0..k).fold(F::one(), |acc, i| acc * F::from((n - i) as i16) / F::from((i + 1) as i16))
+}
+
+/// Polynomial in the Bernstein basis on [0, 1]: b_0*B_0,n(t) + ... + b_n*B_n,n(t),
+/// where B_i,n(t) = C(n, i) * t^i * (1 - t)^(n - i).
+///
+/// Unlike other polynomials, coefficients are control values ordered from t = 0 to t = 1,
+/// like control points of a Bézier curve.
+///
+/// # Examples
+///
+/// ```
+/// use roots::BernsteinPolynomial;
+/// use roots::SimpleConvergency;
+///
+/// // (1 - t)^2 - 4t(1 - t) + t^2 has roots 0.5 ± sqrt(3) / 6
+/// let p = BernsteinPolynomial::new(vec![1f64, -2f64, 1f64]);
+/// assert_eq!(p.eval(0.5f64), -0.5f64);
+///
+/// let mut convergency = SimpleConvergency { eps: 1e-15f64, max_iter: 100 };
+/// let roots = p.roots(&mut convergency).unwrap();
+/// // Returns [0.21132486540518713, 0.7886751345948129]
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct BernsteinPolynomial<F: FloatType> {
+    coefficients: Vec<F>,
+}
+
+impl<F: FloatType> BernsteinPolynomial<F> {
+    /// Creates a polynomial from control values b_0, ..., b_n
+    pub fn new(coefficients: Vec<F>) -> Self {
+        let mut coefficients = coefficients;
+        if coefficients.is_empty() {
+            coefficients.push(F::zero());
+        }
+        Self { coefficients }
+    }
+
+    /// Converts a polynomial with coefficients starting from the highest degree to the Bernstein basis
+    pub fn from_power_basis(coefficients: &[F]) -> Self {
+        let a: Vec<F> = coefficients.iter().rev().cloned().collect();
+        let n = a.len().max(1) - 1;
+        // b_i = sum of C(i, k) / C(n, k) * a_k for k <= i
+        Self::new(
+            (0..=n)
+                .map(|i| {
+                    a.iter()
+                        .enumerate()
+                        .take(i + 1)
+                        .fold(F::zero(), |acc, (k, &a_k)| acc + binomial::<F>(i, k) / binomial::<F>(n, k) * a_k)
+                })
+                .collect(),
+        )
+    }
+
+    /// Coefficients in the power basis starting from the highest degree
+    pub fn to_power_basis(&self) -> Vec<F> {
+        // a_k = C(n, k) times the k-th forward difference of the control values
+        let n = self.degree();
+        let mut differences = self.coefficients.clone();
+        let mut a = Vec::with_capacity(n + 1);
+        for k in 0..=n {
+            a.push(binomial::<F>(n, k) * differences[0]);
+            for i in 0..n - k {
+                differences[i] = differences[i + 1] - differences[i];
+            }
+        }
+        a.reverse();
+        a
+    }
+
+    /// Control values b_0, ..., b_n
+    pub fn coefficients(&self) -> &[F] {
+        &self.coefficients
+    }
+
+    /// Degree of the basis, which may exceed the actual degree of the polynomial
+    pub fn degree(&self) -> usize {
+        self.coefficients.len() - 1
+    }
+
+    /// Value at t by the de Casteljau algorithm
+    pub fn eval(&self, t: F) -> F {
+        let mut b = self.coefficients.clone();
+        for k in (1..b.len()).rev() {
+            for i in 0..k {
+                b[i] = b[i] + (b[i + 1] - b[i]) * t;
+            }
+        }
+        b[0]
+    }
+
+    /// Splits the polynomial at t by the de Casteljau algorithm into polynomials on [0, t] and [t, 1],
+    /// both reparametrized to [0, 1]
+    pub fn subdivide(&self, t: F) -> (Self, Self) {
+        let n = self.degree();
+        let mut b = self.coefficients.clone();
+        let mut left = Vec::with_capacity(n + 1);
+        let mut right = Vec::with_capacity(n + 1);
+        left.push(b[0]);
+        right.push(b[n]);
+        for k in (1..=n).rev() {
+            for i in 0..k {
+                b[i] = b[i] + (b[i + 1] - b[i]) * t;
+            }
+            left.push(b[0]);
+            right.push(b[k - 1]);
+        }
+        right.reverse();
+        (Self::new(left), Self::new(right))
+    }
+
+    /// Part of [0, 1] where the convex hull of the control points (i / n, b_i) meets the t axis
+    fn clip(&self) -> Option<(F, F)> {
+        let n = self.degree();
+        let b = &self.coefficients;
+        let n_f = F::from(n as i16);
+        let mut range: Option<(F, F)> = None;
+        let mut include = |t: F| {
+            range = Some(match range {
+                Some((min, max)) => (if t < min { t } else { min }, if t > max { t } else { max }),
+                None => (t, t),
+            });
+        };
+        for i in 0..=n {
+            if b[i] == F::zero() {
+                include(F::from(i as i16) / n_f);
+            }
+            for j in i + 1..=n {
+                if (b[i] < F::zero() && b[j] > F::zero()) || (b[i] > F::zero() && b[j] < F::zero()) {
+                    let crossing = F::from(i as i16) + F::from((j - i) as i16) * b[i] / (b[i] - b[j]);
+                    include(crossing / n_f);
+                }
+            }
+        }
+        range
+    }
+
+    /// Divides out the factors t and 1 - t of roots at the ends, where the end control values are exactly zero.
+    ///
+    /// Returns the quotient and whether 0 and 1 are roots.
+    fn divide_out_ends(&self) -> (Self, bool, bool) {
+        let mut b = self.coefficients.clone();
+        let (mut root_at_0, mut root_at_1) = (false, false);
+        // t * sum c_j C(n - 1, j) t^j (1 - t)^(n - 1 - j) with c_j = b_(j + 1) * n / (j + 1)
+        while b.len() > 1 && b[0] == F::zero() {
+            let n = F::from((b.len() - 1) as i16);
+            b = b[1..]
+                .iter()
+                .enumerate()
+                .map(|(j, &b)| b * n / F::from((j + 1) as i16))
+                .collect();
+            root_at_0 = true;
+        }
+        // (1 - t) * sum c_i C(n - 1, i) t^i (1 - t)^(n - 1 - i) with c_i = b_i * n / (n - i)
+        while b.len() > 1 && b[b.len() - 1] == F::zero() {
+            let n = b.len() - 1;
+            let n_f = F::from(n as i16);
+            b = b[..n]
+                .iter()
+                .enumerate()
+                .map(|(i, &b)| b * n_f / F::from((n - i) as i16))
+                .collect();
+            root_at_1 = true;
+        }
+        (Self::new(b), root_at_0, root_at_1)
+    }
+
+    /// Bound on the rounding error of control values computed by the de Casteljau algorithm
+    fn rounding_error(&self) -> F {
+        let max = self
+            .coefficients
+            .iter()
+            .fold(F::zero(), |max, &b| if b.abs() > max { b.abs() } else { max });
+        F::four() * F::from(self.coefficients.len() as i16) * F::epsilon() * max
+    }
+
+    /// Finds roots in [t0, t1] that rounding has moved off the t axis, where the convex hull
+    /// of the control points does not meet it: the ends and the extrema with values within the tolerance,
+    /// which include roots of even multiplicity, or the whole interval if the polynomial is negligible on it
+    fn find_roots_near_axis<C>(
+        &self,
+        t0: F,
+        t1: F,
+        tolerance: F,
+        roots: &mut RootsVec<F>,
+        convergency: &mut C,
+    ) -> Result<(), SearchError>
+    where
+        C: Convergency<F>,
+    {
+        let b = &self.coefficients;
+        if b.iter().all(|&b| b.abs() > tolerance) {
+            return Ok(());
+        }
+        if b.iter().all(|&b| b.abs() <= tolerance) {
+            roots.add_new_root((t0 + t1) / F::two());
+            return Ok(());
+        }
+        if b[0].abs() <= tolerance {
+            roots.add_new_root(t0);
+        }
+        if b[self.degree()].abs() <= tolerance {
+            roots.add_new_root(t1);
+        }
+        // Extrema are roots of the derivative, which exist if its control values change the sign
+        let n_f = F::from(self.degree() as i16);
+        let derivative = Self::new(b.windows(2).map(|b| n_f * (b[1] - b[0])).collect());
+        let d = derivative.coefficients();
+        if d.iter().any(|&d| d > F::zero()) && d.iter().any(|&d| d < F::zero()) {
+            for &t in derivative.roots(convergency)?.as_slice() {
+                if self.eval(t).abs() <= tolerance {
+                    roots.add_new_root(t0 + (t1 - t0) * t);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Finds roots in [t0, t1] of the polynomial reparametrized from it by Bézier clipping.
+    ///
+    /// The depth counts halvings of intervals; clipping reduces them by at least a fifth each time.
+    fn find_roots_clipping<C>(
+        &self,
+        t0: F,
+        t1: F,
+        tolerance: F,
+        depth: usize,
+        roots: &mut RootsVec<F>,
+        convergency: &mut C,
+    ) -> Result<(), SearchError>
+    where
+        C: Convergency<F>,
+    {
+        if convergency.is_iteration_limit_reached(depth) {
+            return Err(SearchError::NoConvergency);
+        }
+
+        // Clipping would converge only linearly to roots at the ends, which are known exactly
+        let (p, root_at_0, root_at_1) = self.divide_out_ends();
+        if root_at_0 {
+            roots.add_new_root(t0);
+        }
+        if root_at_1 {
+            roots.add_new_root(t1);
+        }
+        if p.degree() == 0 {
+            return Ok(());
+        }
+
+        let (min, max) = match p.clip() {
+            Some(range) => range,
+            None => return p.find_roots_near_axis(t0, t1, tolerance, roots, convergency),
+        };
+        let (a, b) = (t0 + (t1 - t0) * min, t0 + (t1 - t0) * max);
+        // The interval stops shrinking at the floating point resolution
+        if convergency.is_converged(b - a) || (a == t0 && b == t1) {
+            roots.add_new_root((a + b) / F::two());
+            return Ok(());
+        }
+
+        if max - min > F::four() / F::five() {
+            // Clipping is slow, possibly due to several roots: halve the interval
+            let (left, right) = p.subdivide(F::one() / F::two());
+            let middle = (t0 + t1) / F::two();
+            left.find_roots_clipping(t0, middle, tolerance, depth + 1, roots, convergency)?;
+            right.find_roots_clipping(middle, t1, tolerance, depth + 1, roots, convergency)
+        } else {
+            let (_, right) = p.subdivide(min);
+            let clipped = if max < F::one() {
+                right.subdivide((max - min) / (F::one() - min)).0
+            } else {
+                right
+            };
+            clipped.find_roots_clipping(a, b, tolerance, depth, roots, convergency)
+        }
+    }
+
+    /// Roots in [0, 1] in ascending order.
+    ///
+    /// Roots of polynomials of degree up to 3 are found by the analytical solvers.
+    /// Higher degrees are solved by Bézier clipping: the interval is reduced to the part where
+    /// the convex hull of the control points meets the t axis, and halved when that part is not
+    /// much smaller, until the Convergency accepts its width. Exact roots at the ends of intervals,
+    /// where control values are zero, are divided out first. Where rounding moves a root off the t axis,
+    /// as it does with tangential roots of even multiplicity, ends and extrema of the interval
+    /// with values within the rounding error are taken as roots. Multiple roots are returned once.
+    ///
+    /// # Failures
+    /// ## NoConvergency
+    /// The number of successive halvings has reached the iteration limit of the Convergency.
+    pub fn roots<C: Convergency<F>>(&self, convergency: &mut C) -> Result<RootsVec<F>, SearchError> {
+        let mut roots = RootsVec::new();
+        if self.coefficients.iter().all(|&b| b == F::zero()) {
+            return Ok(roots);
+        }
+
+        if self.degree() <= 3 {
+            let tolerance = F::four() * F::epsilon();
+            if let Some(power_roots) = super::find_roots_analytical(&self.to_power_basis()) {
+                for t in power_roots.filter(|&t| t >= -tolerance && t <= F::one() + tolerance) {
+                    roots.add_new_root(if t < F::zero() { F::zero() } else if t > F::one() { F::one() } else { t });
+                }
+            }
+            return Ok(roots);
+        }
+
+        let tolerance = self.rounding_error();
+        let mut found = RootsVec::new();
+        self.find_roots_clipping(F::zero(), F::one(), tolerance, 0, &mut found, convergency)?;
+        // A root at the end of a halved interval is found on both sides, and rounding may split
+        // a multiple root into close ones with the polynomial negligible between them
+        let mut cluster: Option<(F, F)> = None;
+        for &t in found.as_slice() {
+            cluster = match cluster {
+                Some((first, last)) if convergency.is_converged(t - last) => Some((first, t)),
+                Some((first, last)) if self.eval((last + t) / F::two()).abs() <= tolerance => Some((first, t)),
+                Some((first, last)) => {
+                    roots.add_new_root((first + last) / F::two());
+                    Some((t, t))
+                }
+                None => Some((t, t)),
+            };
+        }
+        if let Some((first, last)) = cluster {
+            roots.add_new_root((first + last) / F::two());
+        }
+        Ok(roots)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::super::*;
+
+    #[test]
+    fn test_power_basis() {
+        // t^3 - 2t + 1
+        let p = BernsteinPolynomial::from_power_basis(&[1f64, 0f64, -2f64, 1f64]);
+        assert_float_array_eq!(1e-15, p.coefficients().iter().cloned(), [1f64, 1f64 / 3f64, -1f64 / 3f64, 0f64]);
+        assert_float_array_eq!(1e-15, p.to_power_basis().into_iter(), [1f64, 0f64, -2f64, 1f64]);
+        for &t in [0f64, 0.25f64, 0.6f64, 1f64].iter() {
+            assert_float_eq!(1e-15, p.eval(t), t * t * t - 2f64 * t + 1f64);
+        }
+    }
+
+    #[test]
+    fn test_subdivide() {
+        let p = BernsteinPolynomial::new(vec![1f64, -3f64, 4f64, 0.5f64, -2f64]);
+        let (left, right) = p.subdivide(0.3f64);
+        assert_eq!(left.coefficients()[0], 1f64);
+        assert_eq!(right.coefficients()[4], -2f64);
+        for &s in [0f64, 0.2f64, 0.5f64, 0.9f64, 1f64].iter() {
+            assert_float_eq!(1e-15, left.eval(s), p.eval(0.3f64 * s));
+            assert_float_eq!(1e-15, right.eval(s), p.eval(0.3f64 + 0.7f64 * s));
+        }
+    }
+
+    #[test]
+    fn test_roots() {
+        let mut convergency = SimpleConvergency { eps: 1e-15f64, max_iter: 100 };
+        assert!(BernsteinPolynomial::new(vec![0f64, 0f64]).roots(&mut convergency).unwrap().as_slice().is_empty());
+        assert!(BernsteinPolynomial::new(vec![1f64, 2f64, 0.5f64, 3f64, 1f64]).roots(&mut convergency).unwrap().as_slice().is_empty());
+
+        // Low degrees use the analytical solvers
+        let p = BernsteinPolynomial::new(vec![1f64, -2f64, 1f64]);
+        assert_float_array_eq!(1e-15, p.roots(&mut convergency).unwrap(), [0.5f64 - 3f64.sqrt() / 6f64, 0.5f64 + 3f64.sqrt() / 6f64]);
+        let p = BernsteinPolynomial::new(vec![0f64, 1f64, -1f64, 1f64]);
+        assert_float_eq!(1e-15, p.roots(&mut convergency).unwrap().as_slice()[0], 0f64);
+
+        // (t - 0.1)(t - 0.35)(t - 0.5)(t - 0.7)(t - 0.9)(t - 2)
+        let expected = [0.1f64, 0.35f64, 0.5f64, 0.7f64, 0.9f64];
+        let p = expected
+            .iter()
+            .chain([2f64].iter())
+            .fold(Polynomial::new(vec![1f64]), |acc, &r| acc * Polynomial::new(vec![1f64, -r]));
+        let p = BernsteinPolynomial::from_power_basis(p.coefficients());
+        assert_float_array_eq!(1e-14, p.roots(&mut convergency).unwrap(), expected);
+
+        // Roots at the ends and in the middle, where intervals are halved: t (t - 0.5) (t - 1) (t - 0.25)
+        let p = BernsteinPolynomial::from_power_basis(&[1f64, -1.75f64, 0.875f64, -0.125f64, 0f64]);
+        assert_float_array_eq!(1e-15, p.roots(&mut convergency).unwrap(), [0f64, 0.25f64, 0.5f64, 1f64]);
+
+        // Tangential roots: (t - 0.3)^2 (t - 2)(t - 3) and (t - 0.3)^2 (t - 0.6)(t - 3)
+        let p = BernsteinPolynomial::from_power_basis(&[1f64, -5.6f64, 9.09f64, -4.05f64, 0.54f64]);
+        assert_float_array_eq!(1e-14, p.roots(&mut convergency).unwrap(), [0.3f64]);
+        let p = BernsteinPolynomial::from_power_basis(&[1f64, -4.2f64, 4.05f64, -1.404f64, 0.162f64]);
+        assert_float_array_eq!(1e-14, p.roots(&mut convergency).unwrap(), [0.3f64, 0.6f64]);
+
+        // Multiple roots at the ends, t^4 and t^2 (1 - t)^3 (t - 0.4), are divided out
+        let p = BernsteinPolynomial::new(vec![0f64, 0f64, 0f64, 0f64, 1f64]);
+        assert_eq!(p.roots(&mut convergency).unwrap().as_slice(), &[0f64]);
+        let p = BernsteinPolynomial::from_power_basis(&[-1f64, 3.4f64, -4.2f64, 2.2f64, -0.4f64, 0f64, 0f64]);
+        assert_float_array_eq!(1e-15, p.roots(&mut convergency).unwrap(), [0f64, 0.4f64, 1f64]);
+
+        // Clipping converges linearly to the multiple root (t - 0.3)^4 (t - 2); only halvings count against the limit
+        let p = BernsteinPolynomial::from_power_basis(&[1f64, -3.2f64, 2.94f64, -1.188f64, 0.2241f64, -0.0162f64]);
+        let mut convergency = SimpleConvergency { eps: 1e-15f64, max_iter: 30 };
+        assert_float_array_eq!(1e-3, p.roots(&mut convergency).unwrap(), [0.3f64]);
+    }
+
+    #[test]
+    fn test_roots_f32() {
+        let mut convergency = SimpleConvergency { eps: 1e-6f32, max_iter: 100 };
+        // (t - 0.25)(t - 0.75)(t^2 + 1)
+        let p = BernsteinPolynomial::from_power_basis(&[1f32, -1f32, 1.1875f32, -1f32, 0.1875f32]);
+        assert_float_array_eq!(1e-6, p.roots(&mut convergency).unwrap(), [0.25f32, 0.75f32]);
+    }
+}
@@ -0,0 +1,189 @@
+// Copyright (c) 2015, Mikhail Vorotilov
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without
+// modification, are permitted provided that the following conditions are met:
+//
+// * Redistributions of source code must retain the above copyright notice, this
+//   list of conditions and the following disclaimer.
+//
+// * Redistributions in binary form must reproduce the above copyright notice,
+//   this list of conditions and the following disclaimer in the documentation
+//   and/or other materials provided with the distribution.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+// AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+// IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+// FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+// DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+// CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+// OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+// OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! A-priori bounds on magnitudes and numbers of roots.
+//!
+//! Upper bounds hold for magnitudes of all complex roots. Lower bounds are upper bounds
+//! for the reciprocal polynomial, whose roots are reciprocals of the roots.
+
+use super::super::FloatType;
+use super::vca::sign_variations;
+
+fn max<F: FloatType>(acc: F, x: F) -> F {
+    if x > acc {
+        x
+    } else {
+        acc
+    }
+}
+
+/// Cauchy bound 1 + max(|a_i / a_n|) on magnitudes of all roots.
+///
+/// Coefficients start from the highest degree. Polynomials without roots have the bound 0.
+pub fn cauchy_bound<F: FloatType>(coefficients: &[F]) -> F {
+    let a = super::trim(coefficients);
+    if a.len() < 2 {
+        return F::zero();
+    }
+    a[1..].iter().fold(F::zero(), |acc, &a_i| max(acc, (a_i / a[0]).abs())) + F::one()
+}
+
+/// Lagrange bound max(1, |a_(n-1) / a_n| + ... + |a_0 / a_n|) on magnitudes of all roots.
+///
+/// Coefficients start from the highest degree. Polynomials without roots have the bound 0.
+pub fn lagrange_bound<F: FloatType>(coefficients: &[F]) -> F {
+    let a = super::trim(coefficients);
+    if a.len() < 2 {
+        return F::zero();
+    }
+    max(F::one(), a[1..].iter().fold(F::zero(), |acc, &a_i| acc + (a_i / a[0]).abs()))
+}
+
+/// Fujiwara bound 2 * max(|a_(n-1) / a_n|, |a_(n-2) / a_n|^(1/2), ..., |a_0 / (2 * a_n)|^(1/n))
+/// on magnitudes of all roots.
+///
+/// Coefficients start from the highest degree. Polynomials without roots have the bound 0.
+pub fn fujiwara_bound<F: FloatType>(coefficients: &[F]) -> F {
+    let a = super::trim(coefficients);
+    if a.len() < 2 {
+        return F::zero();
+    }
+    let n = a.len() - 1;
+    let m = a[1..].iter().enumerate().fold(F::zero(), |acc, (i, &a_i)| {
+        let mut ratio = (a_i / a[0]).abs();
+        if i + 1 == n {
+            ratio = ratio / F::two();
+        }
+        max(acc, ratio.powf(F::one() / F::from((i + 1) as i16)))
+    });
+    F::two() * m
+}
+
+/// Kojima bound max(2|a_(n-1) / a_n|, 2|a_(n-2) / a_(n-1)|, ..., 2|a_1 / a_2|, |a_0 / a_1|)
+/// on magnitudes of all roots, often the tightest one for coefficients of similar magnitudes.
+///
+/// Coefficients start from the highest degree. Returns None if a coefficient
+/// between the highest and the constant one is zero.
+pub fn kojima_bound<F: FloatType>(coefficients: &[F]) -> Option<F> {
+    let a = super::trim(coefficients);
+    if a.len() < 2 {
+        return Some(F::zero());
+    }
+    if a[1..a.len() - 1].iter().any(|&a_i| a_i == F::zero()) {
+        return None;
+    }
+    let n = a.len() - 1;
+    Some(a.windows(2).enumerate().fold(F::zero(), |acc, (i, pair)| {
+        let ratio = (pair[1] / pair[0]).abs();
+        max(acc, if i + 1 == n { ratio } else { F::two() * ratio })
+    }))
+}
+
+/// Lower bound on magnitudes of all non-zero roots from an upper bound function,
+/// as the reciprocal of the upper bound for the reciprocal polynomial.
+///
+/// Coefficients start from the highest degree. Returns 0 if 0 is a root.
+///
+/// # Examples
+///
+/// ```
+/// use roots::{cauchy_bound, lower_bound};
+///
+/// // Roots 0.5 and 4
+/// let coefficients = [1f64, -4.5f64, 2f64];
+/// assert_eq!(cauchy_bound(&coefficients), 5.5f64);
+/// assert_eq!(lower_bound(&coefficients, cauchy_bound), 1f64 / 3.25f64);
+/// ```
+pub fn lower_bound<F, B>(coefficients: &[F], upper_bound: B) -> F
+where
+    F: FloatType,
+    B: Fn(&[F]) -> F,
+{
+    let a = super::trim(coefficients);
+    if a.len() < 2 || a[a.len() - 1] == F::zero() {
+        return F::zero();
+    }
+    let reciprocal: Vec<F> = a.iter().rev().cloned().collect();
+    F::one() / upper_bound(&reciprocal)
+}
+
+/// Maximal numbers of positive and negative roots by the Descartes' rule of signs,
+/// counted with multiplicities. The actual numbers are less by even numbers.
+///
+/// Coefficients start from the highest degree.
+pub fn descartes_root_counts<F: FloatType>(coefficients: &[F]) -> (usize, usize) {
+    let n = coefficients.len();
+    // Coefficients of p(-x)
+    let reflected: Vec<F> = coefficients
+        .iter()
+        .enumerate()
+        .map(|(i, &a)| if (n - 1 - i) % 2 == 1 { -a } else { a })
+        .collect();
+    (sign_variations(coefficients), sign_variations(&reflected))
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::super::*;
+
+    #[test]
+    fn test_upper_bounds() {
+        // (x - 1)(x + 2)(x - 3) = x^3 - 2x^2 - 5x + 6
+        let coefficients = [0f64, 1f64, -2f64, -5f64, 6f64];
+        assert_eq!(cauchy_bound(&coefficients), 7f64);
+        assert_eq!(lagrange_bound(&coefficients), 13f64);
+        assert_float_eq!(1e-15, fujiwara_bound(&coefficients), 2f64 * 5f64.sqrt());
+        assert_eq!(kojima_bound(&coefficients), Some(5f64));
+        for bound in [cauchy_bound(&coefficients), lagrange_bound(&coefficients), fujiwara_bound(&coefficients)].iter() {
+            assert!(*bound >= 3f64);
+        }
+
+        assert_eq!(kojima_bound(&[1f64, 0f64, -4f64]), None);
+        assert_eq!(kojima_bound(&[1f64, 2f64, 0f64]), Some(4f64));
+        assert_eq!(cauchy_bound(&[3f32]), 0f32);
+        assert_eq!(lagrange_bound::<f32>(&[]), 0f32);
+        assert_eq!(fujiwara_bound(&[0f32, 2f32]), 0f32);
+    }
+
+    #[test]
+    fn test_lower_bound() {
+        // Roots 1, -2, 3
+        let coefficients = [1f64, -2f64, -5f64, 6f64];
+        for &bound in [lower_bound(&coefficients, cauchy_bound), lower_bound(&coefficients, lagrange_bound)].iter() {
+            assert!(bound > 0f64 && bound <= 1f64);
+        }
+        let kojima = lower_bound(&coefficients, |a| kojima_bound(a).unwrap());
+        assert_float_eq!(1e-15, kojima, 0.6f64);
+        assert_eq!(lower_bound(&[1f64, 1f64, 0f64], cauchy_bound), 0f64);
+    }
+
+    #[test]
+    fn test_descartes_root_counts() {
+        // (x - 1)(x + 2)(x - 3)
+        assert_eq!(descartes_root_counts(&[1f64, -2f64, -5f64, 6f64]), (2, 1));
+        // x^4 + 1 has no real roots, x^2 + x has a zero root
+        assert_eq!(descartes_root_counts(&[1f64, 0f64, 0f64, 0f64, 1f64]), (0, 0));
+        assert_eq!(descartes_root_counts(&[1f32, 1f32, 0f32]), (0, 1));
+    }
+}
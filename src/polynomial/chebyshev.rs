@@ -0,0 +1,270 @@
+// Copyright (c) 2015, Mikhail Vorotilov
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without
+// modification, are permitted provided that the following conditions are met:
+//
+// * Redistributions of source code must retain the above copyright notice, this
+//   list of conditions and the following disclaimer.
+//
+// * Redistributions in binary form must reproduce the above copyright notice,
+//   this list of conditions and the following disclaimer in the documentation
+//   and/or other materials provided with the distribution.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+// AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+// IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+// FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+// DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+// CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+// OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+// OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use super::super::Complex;
+use super::super::FloatType;
+use super::super::SearchError;
+use super::RootsVec;
+
+/// Series of higher degrees are split before solving by the colleague matrix
+const SPLIT_DEGREE: usize = 50;
+
+/// Intervals are not split deeper, in case coefficients do not decay
+const MAX_SPLIT_DEPTH: usize = 12;
+
+/// Chebyshev series c_n*T_n(t) + ... + c_1*T_1(t) + c_0 on an interval [a, b],
+/// where t = (2x - a - b) / (b - a) maps [a, b] onto [-1, 1].
+///
+/// Coefficients start from the highest degree, like coefficients of all other polynomials.
+/// Series avoid the ill-conditioned monomial basis; roots are found in the Chebyshev basis directly.
+///
+/// # Examples
+///
+/// ```
+/// use roots::ChebyshevSeries;
+///
+/// // T_2(x) = 2x^2 - 1 on [-1, 1]
+/// let t2 = ChebyshevSeries::new(vec![1f64, 0f64, 0f64], -1f64, 1f64);
+/// assert_eq!(t2.eval(0.5f64), -0.5f64);
+///
+/// let roots = t2.roots().unwrap();
+/// // Returns [-0.7071067811865475, 0.7071067811865475]
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChebyshevSeries<F: FloatType> {
+    coefficients: Vec<F>,
+    a: F,
+    b: F,
+}
+
+impl<F: FloatType> ChebyshevSeries<F> {
+    /// Creates a series on [a, b] from coefficients starting from the highest degree
+    pub fn new(coefficients: Vec<F>, a: F, b: F) -> Self {
+        let mut coefficients = coefficients;
+        let leading_zeros = coefficients.len() - super::trim(&coefficients).len();
+        coefficients.drain(..leading_zeros);
+        if coefficients.is_empty() {
+            coefficients.push(F::zero());
+        }
+        Self { coefficients, a, b }
+    }
+
+    /// Interpolates f on [a, b] in the Chebyshev points of the first kind by a series of the given degree
+    pub fn from_function<Func>(f: Func, degree: usize, a: F, b: F) -> Self
+    where
+        Func: Fn(F) -> F,
+    {
+        let n = F::from((degree + 1) as i16);
+        let (middle, half) = ((a + b) / F::two(), (b - a) / F::two());
+        let angles: Vec<F> = (0..=degree)
+            .map(|k| F::pi() * (F::from(k as i16) + F::one() / F::two()) / n)
+            .collect();
+        let values: Vec<F> = angles.iter().map(|&angle| f(middle + half * angle.cos())).collect();
+        let coefficients = (0..=degree)
+            .rev()
+            .map(|j| {
+                let sum = angles
+                    .iter()
+                    .zip(values.iter())
+                    .fold(F::zero(), |acc, (&angle, &y)| acc + y * (F::from(j as i16) * angle).cos());
+                if j == 0 {
+                    sum / n
+                } else {
+                    F::two() * sum / n
+                }
+            })
+            .collect();
+        Self::new(coefficients, a, b)
+    }
+
+    /// Coefficients starting from the highest degree
+    pub fn coefficients(&self) -> &[F] {
+        &self.coefficients
+    }
+
+    /// The interval [a, b]
+    pub fn interval(&self) -> (F, F) {
+        (self.a, self.b)
+    }
+
+    /// Degree of the series. The degree of the zero series is 0.
+    pub fn degree(&self) -> usize {
+        self.coefficients.len() - 1
+    }
+
+    /// Value of the series at x by the Clenshaw recurrence
+    pub fn eval(&self, x: F) -> F {
+        let t = (F::two() * x - self.a - self.b) / (self.b - self.a);
+        let n = self.degree();
+        let (b1, b2) = self.coefficients[..n]
+            .iter()
+            .fold((F::zero(), F::zero()), |(b1, b2), &c| (c + F::two() * t * b1 - b2, b1));
+        self.coefficients[n] + t * b1 - b2
+    }
+
+    /// Removes the highest coefficients below the rounding errors of the interpolation
+    fn chop(&self) -> Self {
+        let max = self.coefficients.iter().fold(F::zero(), |acc, &c| if c.abs() > acc { c.abs() } else { acc });
+        let tolerance = F::epsilon() * F::from(self.coefficients.len() as i16) * max;
+        let negligible = self.coefficients.iter().take_while(|c| c.abs() <= tolerance).count();
+        Self::new(self.coefficients[negligible.min(self.degree())..].to_vec(), self.a, self.b)
+    }
+
+    /// Real roots in [-1, 1] of the series as eigenvalues of the colleague matrix
+    fn colleague_roots(&self) -> Result<Vec<F>, SearchError> {
+        let n = self.degree();
+        let c = &self.coefficients;
+        let eigenvalues = if n == 1 {
+            vec![Complex::from(-c[1] / c[0])]
+        } else {
+            // Transposed colleague matrix, which is upper Hessenberg: x*T_0 = T_1,
+            // x*T_k = (T_(k-1) + T_(k+1)) / 2 and T_n is replaced using the series
+            let mut m = vec![vec![F::zero(); n]; n];
+            m[1][0] = F::one();
+            for k in 1..n - 1 {
+                m[k - 1][k] = F::one() / F::two();
+                m[k + 1][k] = F::one() / F::two();
+            }
+            m[n - 2][n - 1] = m[n - 2][n - 1] + F::one() / F::two();
+            for j in 0..n {
+                // c[n - j] is the coefficient of T_j
+                m[j][n - 1] = m[j][n - 1] - c[n - j] / (F::two() * c[0]);
+            }
+            super::eigen::balance(&mut m);
+            super::eigen::hessenberg_eigenvalues(&mut m)?
+        };
+
+        let tolerance = F::epsilon().sqrt();
+        Ok(eigenvalues
+            .into_iter()
+            .filter(|z| z.im.abs() <= tolerance && z.re.abs() <= F::one() + tolerance)
+            .map(|z| if z.re > F::one() { F::one() } else if z.re < -F::one() { -F::one() } else { z.re })
+            .collect())
+    }
+
+    fn find_roots(&self, roots: &mut RootsVec<F>, depth: usize) -> Result<(), SearchError> {
+        let series = self.chop();
+        let n = series.degree();
+        if n == 0 {
+            return Ok(());
+        }
+        let (a, b) = (self.a, self.b);
+        if n <= SPLIT_DEGREE || depth == MAX_SPLIT_DEPTH {
+            let (middle, half) = ((a + b) / F::two(), (b - a) / F::two());
+            for t in series.colleague_roots()? {
+                roots.add_new_root(middle + half * t);
+            }
+            return Ok(());
+        }
+
+        // Restrictions to both halves need fewer coefficients. The split point is moved
+        // off the middle, where roots of symmetric functions often are.
+        let split = (a + b) / F::two() + (b - a) * F::from(-4849i16) / F::from(10000i16) / F::from(100i16);
+        let mut left = RootsVec::new();
+        ChebyshevSeries::from_function(|x| series.eval(x), n, a, split).find_roots(&mut left, depth + 1)?;
+        let mut right = RootsVec::new();
+        ChebyshevSeries::from_function(|x| series.eval(x), n, split, b).find_roots(&mut right, depth + 1)?;
+
+        // A root at the split point may be found in both halves
+        let last_left = left.as_slice().last().cloned();
+        let tolerance = F::epsilon().sqrt() * (b - a);
+        for &x in left.as_slice() {
+            roots.add_new_root(x);
+        }
+        for (i, &x) in right.as_slice().iter().enumerate() {
+            if i == 0 && matches!(last_left, Some(y) if x - y <= tolerance) {
+                continue;
+            }
+            roots.add_new_root(x);
+        }
+        Ok(())
+    }
+
+    /// Real roots in [a, b] in ascending order.
+    ///
+    /// Series up to degree 50 are solved as eigenvalues of the colleague matrix;
+    /// series of higher degrees are recursively split into two intervals first.
+    ///
+    /// # Failures
+    /// ## NoConvergency
+    /// Eigenvalues of a colleague matrix could not be found.
+    pub fn roots(&self) -> Result<RootsVec<F>, SearchError> {
+        let mut roots = RootsVec::new();
+        self.find_roots(&mut roots, 0)?;
+        Ok(roots)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::super::*;
+
+    #[test]
+    fn test_eval() {
+        // 3*T_2 + 2*T_1 + 1 on [0, 2], where t = x - 1
+        let series = ChebyshevSeries::new(vec![3f64, 2f64, 1f64], 0f64, 2f64);
+        for &x in [0f64, 0.5f64, 1f64, 1.7f64, 2f64].iter() {
+            let t = x - 1f64;
+            assert_float_eq!(1e-15, series.eval(x), 3f64 * (2f64 * t * t - 1f64) + 2f64 * t + 1f64);
+        }
+        assert_eq!(ChebyshevSeries::new(vec![0f64, 5f64], 0f64, 1f64).eval(0.3f64), 5f64);
+        assert_eq!(ChebyshevSeries::new(vec![0f64, 5f64], 0f64, 1f64).degree(), 0);
+    }
+
+    #[test]
+    fn test_from_function() {
+        // x^3 = (T_3 + 3*T_1) / 4
+        let series = ChebyshevSeries::from_function(|x| x * x * x, 3, -1f64, 1f64);
+        assert_float_array_eq!(1e-15, series.coefficients().iter().cloned(), [0.25f64, 0f64, 0.75f64, 0f64]);
+        assert_eq!(series.interval(), (-1f64, 1f64));
+    }
+
+    #[test]
+    fn test_roots() {
+        // T_5 has roots cos((2k - 1) * pi / 10)
+        let t5 = ChebyshevSeries::new(vec![1f64, 0f64, 0f64, 0f64, 0f64, 0f64], -1f64, 1f64);
+        let expected: Vec<f64> = (1..=5).rev().map(|k| ((2 * k - 1) as f64 * core::f64::consts::PI / 10f64).cos()).collect();
+        assert_float_array_eq!(1e-15, t5.roots().unwrap(), expected);
+
+        // No roots in the interval: (x - 3)(x + 3) on [-2, 2]
+        let p = ChebyshevSeries::from_function(|x| x * x - 9f64, 2, -2f64, 2f64);
+        assert!(p.roots().unwrap().as_slice().is_empty());
+
+        let line = ChebyshevSeries::new(vec![2f32, 1f32], 0f32, 4f32);
+        assert_float_array_eq!(1e-6, line.roots().unwrap(), [1f32]);
+
+        // Roots of a line outside the interval are dropped
+        assert!(ChebyshevSeries::new(vec![1f64, 5f64], 0f64, 1f64).roots().unwrap().as_slice().is_empty());
+        assert!(ChebyshevSeries::from_function(|x: f64| x - 3f64, 3, -1f64, 1f64).roots().unwrap().as_slice().is_empty());
+        assert_float_array_eq!(1e-15, ChebyshevSeries::new(vec![1f64, -1f64], 0f64, 4f64).roots().unwrap(), [4f64]);
+    }
+
+    #[test]
+    fn test_roots_high_degree() {
+        // cos(20x) on [0, 10] has 64 roots (2k + 1) * pi / 40
+        let series = ChebyshevSeries::from_function(|x: f64| (20f64 * x).cos(), 300, 0f64, 10f64);
+        let expected: Vec<f64> = (0..64).map(|k| (2 * k + 1) as f64 * core::f64::consts::PI / 40f64).collect();
+        assert_float_array_eq!(1e-12, series.roots().unwrap(), expected);
+    }
+}
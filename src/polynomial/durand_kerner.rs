@@ -0,0 +1,213 @@
+// Copyright (c) 2015, Mikhail Vorotilov
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without
+// modification, are permitted provided that the following conditions are met:
+//
+// * Redistributions of source code must retain the above copyright notice, this
+//   list of conditions and the following disclaimer.
+//
+// * Redistributions in binary form must reproduce the above copyright notice,
+//   this list of conditions and the following disclaimer in the documentation
+//   and/or other materials provided with the distribution.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+// AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+// IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+// FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+// DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+// CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+// OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+// OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use super::super::Complex;
+use super::super::Convergency;
+use super::super::FloatType;
+use super::super::SearchError;
+
+use core::cmp::Ordering;
+
+/// Replaces nearly real approximations by real numbers and averages approximations
+/// of complex conjugate pairs. Errors are estimated by the last Weierstrass corrections.
+fn clean_conjugate_pairs<F: FloatType>(roots: &mut [Complex<F>], errors: &[F]) {
+    let n = F::from(roots.len() as i16);
+    for (z, &error) in roots.iter_mut().zip(errors.iter()) {
+        if z.im.abs() <= n * error {
+            z.im = F::zero();
+        }
+    }
+
+    let mut paired = vec![false; roots.len()];
+    for i in 0..roots.len() {
+        if roots[i].im <= F::zero() || paired[i] {
+            continue;
+        }
+        // The closest unpaired approximation to the conjugate
+        let conjugate = roots
+            .iter()
+            .enumerate()
+            .filter(|&(j, z)| z.im < F::zero() && !paired[j])
+            .map(|(j, &z)| (j, (z - roots[i].conj()).abs()))
+            .min_by(|x, y| x.1.partial_cmp(&y.1).unwrap_or(Ordering::Equal));
+        if let Some((j, _)) = conjugate {
+            let average = Complex::new(roots[i].re + roots[j].re, roots[i].im - roots[j].im) / F::two();
+            roots[i] = average;
+            roots[j] = average.conj();
+            paired[i] = true;
+            paired[j] = true;
+        }
+    }
+
+    // An odd number of non-real approximations cannot come from real coefficients
+    for (z, &paired) in roots.iter_mut().zip(paired.iter()) {
+        if z.im != F::zero() && !paired {
+            z.im = F::zero();
+        }
+    }
+}
+
+/// Finds all complex roots of a polynomial by the Durand-Kerner (Weierstrass) method.
+///
+/// Coefficients start from the highest degree. Approximations are updated one after another
+/// by z_i -= p(z_i) / (a_n * prod(z_i - z_j)), each with the latest values of the others
+/// (the serial, Gauss-Seidel variant, which converges faster than the simultaneous update),
+/// until the Convergency accepts either all |p(z_i)| or the largest correction. An approximation
+/// stops moving once p(z_i) vanishes within rounding errors. Since coefficients are real, nearly real
+/// approximations are made real and the others are made exact complex conjugate pairs.
+/// Roots are ordered by the real part, then by the imaginary one.
+///
+/// # Failures
+/// ## NoConvergency
+/// Approximations have not converged within the given number of iterations.
+///
+/// # Examples
+///
+/// ```
+/// use roots::find_roots_durand_kerner;
+/// use roots::SimpleConvergency;
+///
+/// // (x - 1)(x^2 + 1) = 0
+/// let mut convergency = SimpleConvergency { eps: 1e-15f64, max_iter: 100 };
+/// let roots = find_roots_durand_kerner(&[1f64, -1f64, 1f64, -1f64], &mut convergency).unwrap();
+/// assert_eq!(roots.len(), 3);
+/// assert_eq!(roots[0].re, roots[1].re);
+/// assert_eq!(roots[0].im, -roots[1].im);
+/// assert_eq!(roots[2].im, 0f64);
+/// ```
+pub fn find_roots_durand_kerner<F, C>(coefficients: &[F], convergency: &mut C) -> Result<Vec<Complex<F>>, SearchError>
+where
+    F: FloatType,
+    C: Convergency<F>,
+{
+    let a = super::trim(coefficients);
+    if a.len() < 2 {
+        return Ok(Vec::new());
+    }
+
+    // Zero roots are known exactly
+    let zero_roots = a.iter().rev().take_while(|&&x| x == F::zero()).count();
+    let a = &a[..a.len() - zero_roots];
+    let n = a.len() - 1;
+
+    // Powers of 0.4 + 0.9i, neither real nor a root of unity, scaled to the mean root magnitude
+    let radius = (a[n] / a[0]).abs().powf(F::one() / F::from(n.max(1) as i16));
+    let seed = Complex::new(F::from(4i16) / F::from(10i16), F::from(9i16) / F::from(10i16));
+    let mut z: Vec<Complex<F>> = Vec::with_capacity(n);
+    let mut power = Complex::from(radius);
+    for _ in 0..n {
+        z.push(power);
+        power = power * seed;
+    }
+
+    let mut errors = vec![F::zero(); n];
+    let mut iter = 0;
+    loop {
+        let mut all_roots_found = true;
+        let mut max_correction = F::zero();
+        for i in 0..n {
+            let p = a.iter().fold(Complex::zero(), |acc, &a_k| acc * z[i] + Complex::from(a_k));
+            let p_abs = p.abs();
+            if p_abs <= super::rounding_error(a.iter().map(|a_k| a_k.abs()), z[i].abs()) {
+                // p(z_i) vanishes within rounding errors, further corrections would be noise
+                continue;
+            }
+            all_roots_found = all_roots_found && convergency.is_root_found(p_abs);
+            let denominator = z
+                .iter()
+                .enumerate()
+                .filter(|&(j, _)| j != i)
+                .fold(Complex::from(a[0]), |acc, (_, &z_j)| acc * (z[i] - z_j));
+            let correction = p / denominator;
+            // Updated in place, so the next approximations use the new value
+            z[i] = z[i] - correction;
+            errors[i] = correction.abs();
+            if errors[i] > max_correction {
+                max_correction = errors[i];
+            }
+        }
+
+        if all_roots_found || convergency.is_converged(max_correction) {
+            break;
+        }
+        iter += 1;
+        if convergency.is_iteration_limit_reached(iter) {
+            return Err(SearchError::NoConvergency);
+        }
+    }
+
+    clean_conjugate_pairs(&mut z, &errors);
+    z.extend((0..zero_roots).map(|_| Complex::zero()));
+    z.sort_by(|x, y| (x.re, x.im).partial_cmp(&(y.re, y.im)).unwrap_or(Ordering::Equal));
+    Ok(z)
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::super::*;
+
+    #[test]
+    fn test_find_roots_durand_kerner() {
+        let mut convergency = SimpleConvergency { eps: 1e-15f64, max_iter: 200 };
+        assert!(find_roots_durand_kerner(&[0f64, 2f64], &mut convergency).unwrap().is_empty());
+
+        // (x + 3)(x - 2)(x^2 + 2x + 5) x^2
+        let roots = find_roots_durand_kerner(&[1f64, 3f64, 1f64, -7f64, -30f64, 0f64, 0f64], &mut convergency).unwrap();
+        assert_eq!(roots.len(), 6);
+        assert_float_eq!(1e-14, roots[0].re, -3f64);
+        assert_eq!(roots[0].im, 0f64);
+        assert_float_eq!(1e-14, roots[1].re, -1f64);
+        assert_float_eq!(1e-14, roots[1].im, -2f64);
+        assert_eq!(roots[2], roots[1].conj());
+        assert_eq!(roots[3], Complex::zero());
+        assert_eq!(roots[4], Complex::zero());
+        assert_float_eq!(1e-14, roots[5].re, 2f64);
+        assert_eq!(roots[5].im, 0f64);
+
+        // |p| near the root at 132.4 cannot get below the tolerance; rounding errors stop the iteration
+        let mut convergency = SimpleConvergency { eps: 1e-14f64, max_iter: 500 };
+        let p = [0.04791990960469672f64, -6.358451682752957f64, 1.7825643245420841f64, -0.3289386146048878f64];
+        let roots = find_roots_durand_kerner(&p, &mut convergency).unwrap();
+        assert_float_eq!(1e-12, roots[2].re, 132.40859372001452f64);
+        assert_eq!(roots[2].im, 0f64);
+
+        let mut convergency = SimpleConvergency { eps: 1e-15f64, max_iter: 2 };
+        assert_eq!(
+            find_roots_durand_kerner(&[1f64, 0f64, 0f64, 0f64, 0f64, 1f64], &mut convergency),
+            Err(SearchError::NoConvergency)
+        );
+    }
+
+    #[test]
+    fn test_find_roots_durand_kerner_f32() {
+        // x^3 - 1
+        let mut convergency = SimpleConvergency { eps: 1e-6f32, max_iter: 100 };
+        let roots = find_roots_durand_kerner(&[1f32, 0f32, 0f32, -1f32], &mut convergency).unwrap();
+        assert_float_eq!(1e-6, roots[0].re, -0.5f32);
+        assert_float_eq!(1e-6, roots[0].im, -0.8660254f32);
+        assert_eq!(roots[1], roots[0].conj());
+        assert_float_eq!(1e-6, roots[2].re, 1f32);
+        assert_eq!(roots[2].im, 0f32);
+    }
+}
@@ -0,0 +1,292 @@
+// Copyright (c) 2015, Mikhail Vorotilov
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without
+// modification, are permitted provided that the following conditions are met:
+//
+// * Redistributions of source code must retain the above copyright notice, this
+//   list of conditions and the following disclaimer.
+//
+// * Redistributions in binary form must reproduce the above copyright notice,
+//   this list of conditions and the following disclaimer in the documentation
+//   and/or other materials provided with the distribution.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+// AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+// IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+// FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+// DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+// CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+// OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+// OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use super::super::FloatType;
+use super::super::Roots;
+
+use core::ops::Add;
+use core::ops::Mul;
+use core::ops::Neg;
+use core::ops::Sub;
+
+/// Polynomial of any degree with coefficients stored in a vector.
+///
+/// Coefficients start from the highest degree. Leading zero coefficients are removed,
+/// so the zero polynomial is represented by the single coefficient 0.
+///
+/// # Examples
+///
+/// ```
+/// use roots::Polynomial;
+///
+/// // x^2 - 1
+/// let p = Polynomial::new(vec![1f64, 0f64, -1f64]);
+/// assert_eq!(p.eval(3f64), 8f64);
+/// assert_eq!(p.derivative().coefficients(), &[2f64, 0f64]);
+///
+/// // (x^2 - 1) * (x + 2) = x^3 + 2x^2 - x - 2
+/// let q = &p * &Polynomial::new(vec![1f64, 2f64]);
+/// assert_eq!(q.coefficients(), &[1f64, 2f64, -1f64, -2f64]);
+///
+/// let roots: Vec<f64> = q.roots().unwrap().collect();
+/// // Returns [-2, -1, 1]
+/// ```
+///
+/// Polynomial<i64> provides exact arithmetic with integer coefficients, see the exact module.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Polynomial<F> {
+    pub(super) coefficients: Vec<F>,
+}
+
+impl<F> Polynomial<F> {
+    /// Coefficients starting from the highest degree
+    pub fn coefficients(&self) -> &[F] {
+        &self.coefficients
+    }
+
+    /// Degree of the polynomial. The degree of the zero polynomial is 0.
+    pub fn degree(&self) -> usize {
+        self.coefficients.len() - 1
+    }
+}
+
+impl<F: FloatType> Polynomial<F> {
+    /// Creates a polynomial from coefficients starting from the highest degree
+    pub fn new(coefficients: Vec<F>) -> Self {
+        let mut coefficients = coefficients;
+        let leading_zeros = coefficients.len() - super::trim(&coefficients).len();
+        coefficients.drain(..leading_zeros);
+        if coefficients.is_empty() {
+            coefficients.push(F::zero());
+        }
+        Self { coefficients }
+    }
+
+    /// The zero polynomial
+    pub fn zero() -> Self {
+        Self::new(Vec::new())
+    }
+
+    /// Value of the polynomial at x
+    pub fn eval(&self, x: F) -> F {
+        super::eval(&self.coefficients, x)
+    }
+
+    /// Value of the polynomial and its first derivative at x
+    pub fn eval_with_derivative(&self, x: F) -> (F, F) {
+        let mut values = [F::zero(); 2];
+        super::eval_derivatives(&self.coefficients, x, &mut values);
+        (values[0], values[1])
+    }
+
+    /// Values of the polynomial and its derivatives at x, as many as the length of values
+    pub fn eval_derivatives(&self, x: F, values: &mut [F]) {
+        super::eval_derivatives(&self.coefficients, x, values)
+    }
+
+    /// The first derivative
+    pub fn derivative(&self) -> Self {
+        let n = self.degree();
+        Self::new(
+            self.coefficients[..n]
+                .iter()
+                .enumerate()
+                .map(|(i, &a)| a * F::from((n - i) as i16))
+                .collect(),
+        )
+    }
+
+    /// The antiderivative with the given value at 0
+    pub fn antiderivative(&self, constant: F) -> Self {
+        let n = self.coefficients.len();
+        let mut coefficients: Vec<F> = self
+            .coefficients
+            .iter()
+            .enumerate()
+            .map(|(i, &a)| a / F::from((n - i) as i16))
+            .collect();
+        coefficients.push(constant);
+        Self::new(coefficients)
+    }
+
+    /// The composition self(other(x))
+    pub fn compose(&self, other: &Self) -> Self {
+        self.coefficients
+            .iter()
+            .fold(Self::zero(), |acc, &a| &(&acc * other) + &Self::new(vec![a]))
+    }
+
+    /// Quotient and remainder of the division by a non-zero polynomial
+    pub fn div_rem(&self, divisor: &Self) -> (Self, Self) {
+        let d = &divisor.coefficients;
+        if d.len() > self.coefficients.len() {
+            return (Self::zero(), self.clone());
+        }
+        let mut remainder = self.coefficients.clone();
+        let mut quotient = Vec::with_capacity(remainder.len() - d.len() + 1);
+        for i in 0..=remainder.len() - d.len() {
+            let q = remainder[i] / d[0];
+            for (r, &d_j) in remainder[i..].iter_mut().zip(d.iter()) {
+                *r = *r - q * d_j;
+            }
+            quotient.push(q);
+        }
+        // The leading coefficients of the remainder are eliminated by the construction
+        let remainder = remainder.split_off(quotient.len());
+        (Self::new(quotient), Self::new(remainder))
+    }
+
+    /// Real roots of a polynomial of degree up to 4, found by the analytical solvers.
+    ///
+    /// Returns None if the degree is higher than 4.
+    pub fn roots(&self) -> Option<Roots<F>> {
+        super::find_roots_analytical(&self.coefficients)
+    }
+}
+
+impl<F: FloatType> From<&[F]> for Polynomial<F> {
+    fn from(coefficients: &[F]) -> Self {
+        Self::new(coefficients.to_vec())
+    }
+}
+
+impl<F: FloatType> Add for &Polynomial<F> {
+    type Output = Polynomial<F>;
+
+    fn add(self, other: Self) -> Polynomial<F> {
+        let (long, short) = if self.coefficients.len() >= other.coefficients.len() {
+            (&self.coefficients, &other.coefficients)
+        } else {
+            (&other.coefficients, &self.coefficients)
+        };
+        let offset = long.len() - short.len();
+        let mut coefficients = long.clone();
+        for (a, &b) in coefficients[offset..].iter_mut().zip(short.iter()) {
+            *a = *a + b;
+        }
+        Polynomial::new(coefficients)
+    }
+}
+
+impl<F: FloatType> Neg for &Polynomial<F> {
+    type Output = Polynomial<F>;
+
+    fn neg(self) -> Polynomial<F> {
+        Polynomial::new(self.coefficients.iter().map(|&a| -a).collect())
+    }
+}
+
+impl<F: FloatType> Sub for &Polynomial<F> {
+    type Output = Polynomial<F>;
+
+    fn sub(self, other: Self) -> Polynomial<F> {
+        self + &(-other)
+    }
+}
+
+impl<F: FloatType> Mul for &Polynomial<F> {
+    type Output = Polynomial<F>;
+
+    fn mul(self, other: Self) -> Polynomial<F> {
+        let mut coefficients = vec![F::zero(); self.coefficients.len() + other.coefficients.len() - 1];
+        for (i, &a) in self.coefficients.iter().enumerate() {
+            for (j, &b) in other.coefficients.iter().enumerate() {
+                coefficients[i + j] = coefficients[i + j] + a * b;
+            }
+        }
+        Polynomial::new(coefficients)
+    }
+}
+
+impl<F: FloatType> Mul<F> for &Polynomial<F> {
+    type Output = Polynomial<F>;
+
+    fn mul(self, factor: F) -> Polynomial<F> {
+        Polynomial::new(self.coefficients.iter().map(|&a| a * factor).collect())
+    }
+}
+
+macro_rules! forward_binary_op(
+    ($imp:ident, $method:ident) => {
+        impl<F: FloatType> $imp for Polynomial<F> {
+            type Output = Polynomial<F>;
+
+            fn $method(self, other: Self) -> Polynomial<F> {
+                (&self).$method(&other)
+            }
+        }
+    }
+);
+
+forward_binary_op!(Add, add);
+forward_binary_op!(Sub, sub);
+forward_binary_op!(Mul, mul);
+
+impl<F: FloatType> Neg for Polynomial<F> {
+    type Output = Polynomial<F>;
+
+    fn neg(self) -> Polynomial<F> {
+        -&self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::super::*;
+
+    #[test]
+    fn test_polynomial_arithmetic() {
+        let p = Polynomial::new(vec![0f64, 1f64, 0f64, -1f64]);
+        let q = Polynomial::new(vec![1f64, 2f64]);
+        assert_eq!(p.degree(), 2);
+        assert_eq!((&p + &q).coefficients(), &[1f64, 1f64, 1f64]);
+        assert_eq!((&q - &q), Polynomial::zero());
+        assert_eq!((p.clone() * q.clone()).coefficients(), &[1f64, 2f64, -1f64, -2f64]);
+        assert_eq!((-q.clone()).coefficients(), &[-1f64, -2f64]);
+        assert_eq!((&q * 2f64).coefficients(), &[2f64, 4f64]);
+        // (x + 2)^2 - 1
+        assert_eq!(p.compose(&q).coefficients(), &[1f64, 4f64, 3f64]);
+
+        let (quotient, remainder) = Polynomial::new(vec![1f64, 2f64, -1f64, -1f64]).div_rem(&q);
+        assert_eq!(quotient.coefficients(), &[1f64, 0f64, -1f64]);
+        assert_eq!(remainder.coefficients(), &[1f64]);
+        assert_eq!(q.div_rem(&p), (Polynomial::zero(), q.clone()));
+    }
+
+    #[test]
+    fn test_polynomial_calculus() {
+        let p = Polynomial::new(vec![4f64, 3f64, 2f64, 1f64]);
+        assert_eq!(p.derivative().coefficients(), &[12f64, 6f64, 2f64]);
+        assert_eq!(p.derivative().antiderivative(1f64), p);
+        assert_eq!(Polynomial::new(vec![5f32]).derivative(), Polynomial::zero());
+        assert_eq!(p.eval_with_derivative(1f64), (10f64, 20f64));
+    }
+
+    #[test]
+    fn test_polynomial_roots() {
+        let p = Polynomial::from(&[1f64, -10f64, 35f64, -50f64, 24f64][..]);
+        assert_float_array_eq!(2e-15f64, p.roots().unwrap(), [1f64, 2f64, 3f64, 4f64]);
+        assert!((&p * &p).roots().is_none());
+    }
+}
@@ -0,0 +1,371 @@
+// Copyright (c) 2015, Mikhail Vorotilov
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without
+// modification, are permitted provided that the following conditions are met:
+//
+// * Redistributions of source code must retain the above copyright notice, this
+//   list of conditions and the following disclaimer.
+//
+// * Redistributions in binary form must reproduce the above copyright notice,
+//   this list of conditions and the following disclaimer in the documentation
+//   and/or other materials provided with the distribution.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+// AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+// IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+// FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+// DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+// CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+// OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+// OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use super::super::Complex;
+use super::super::FloatType;
+use super::super::SearchError;
+use super::RootsVec;
+
+/// Balances a square matrix to reduce the norm before the eigenvalue calculation.
+///
+/// Rows and columns are scaled by powers of 2, so no rounding errors are introduced.
+pub(super) fn balance<F: FloatType>(a: &mut [Vec<F>]) {
+    let n = a.len();
+    let radix = F::two();
+    let radix_sqr = radix * radix;
+    let threshold = F::from(19i16) / F::from(20i16);
+    let mut done = false;
+    while !done {
+        done = true;
+        for i in 0..n {
+            let (mut c, mut r) = (F::zero(), F::zero());
+            for j in (0..n).filter(|&j| j != i) {
+                c = c + a[j][i].abs();
+                r = r + a[i][j].abs();
+            }
+            if c != F::zero() && r != F::zero() {
+                let s = c + r;
+                let mut f = F::one();
+                let mut g = r / radix;
+                while c < g {
+                    f = f * radix;
+                    c = c * radix_sqr;
+                }
+                g = r * radix;
+                while c > g {
+                    f = f / radix;
+                    c = c / radix_sqr;
+                }
+                if (c + r) / f < threshold * s {
+                    done = false;
+                    g = F::one() / f;
+                    for x in a[i].iter_mut() {
+                        *x = *x * g;
+                    }
+                    for row in a.iter_mut() {
+                        row[i] = row[i] * f;
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Returns |a| with the sign of b
+fn sign<F: FloatType>(a: F, b: F) -> F {
+    if b >= F::zero() {
+        a.abs()
+    } else {
+        -a.abs()
+    }
+}
+
+/// Finds all eigenvalues of an upper Hessenberg matrix by the Francis double-shift QR algorithm.
+///
+/// The matrix is destroyed. See Numerical Recipes, hqr.
+pub(super) fn hessenberg_eigenvalues<F: FloatType>(a: &mut [Vec<F>]) -> Result<Vec<Complex<F>>, SearchError> {
+    let n = a.len();
+    let mut eigenvalues = Vec::with_capacity(n);
+
+    let mut anorm = F::zero();
+    for (i, row) in a.iter().enumerate() {
+        for x in row[i.max(1) - 1..].iter() {
+            anorm = anorm + x.abs();
+        }
+    }
+
+    // Exceptional shift constants
+    let (three_quarters, seven_sixteenths) = (F::three() / F::four(), F::from(7i16) / F::from(16i16));
+
+    // Eigenvalues are found from the bottom of the matrix; nn is the size of the active part
+    let mut nn = n;
+    let mut t = F::zero();
+    while nn > 0 {
+        let last = nn - 1;
+        let mut its = 0;
+        loop {
+            // Look for a single small subdiagonal element
+            let mut l = last;
+            while l > 0 {
+                let mut s = a[l - 1][l - 1].abs() + a[l][l].abs();
+                if s == F::zero() {
+                    s = anorm;
+                }
+                if a[l][l - 1].abs() <= F::epsilon() * s {
+                    a[l][l - 1] = F::zero();
+                    break;
+                }
+                l -= 1;
+            }
+
+            let mut x = a[last][last];
+            if l == last {
+                // One root found
+                eigenvalues.push(Complex::from(x + t));
+                nn -= 1;
+                break;
+            }
+
+            let mut y = a[last - 1][last - 1];
+            let mut w = a[last][last - 1] * a[last - 1][last];
+            if l + 1 == last {
+                // Two roots found
+                let p = (y - x) / F::two();
+                let q = p * p + w;
+                let z = q.abs().sqrt();
+                x = x + t;
+                if q >= F::zero() {
+                    // A real pair
+                    let z = p + sign(z, p);
+                    eigenvalues.push(Complex::from(x + z));
+                    eigenvalues.push(Complex::from(if z != F::zero() { x - w / z } else { x + z }));
+                } else {
+                    // A complex pair
+                    eigenvalues.push(Complex::new(x + p, z));
+                    eigenvalues.push(Complex::new(x + p, -z));
+                }
+                nn -= 2;
+                break;
+            }
+
+            if its == 30 {
+                return Err(SearchError::NoConvergency);
+            }
+            if its == 10 || its == 20 {
+                // Exceptional shift
+                t = t + x;
+                for (i, row) in a.iter_mut().enumerate().take(last + 1) {
+                    row[i] = row[i] - x;
+                }
+                let s = a[last][last - 1].abs() + a[last - 1][last - 2].abs();
+                x = three_quarters * s;
+                y = x;
+                w = -seven_sixteenths * s * s;
+            }
+            its += 1;
+
+            // Form the shift and look for two consecutive small subdiagonal elements
+            let (mut p, mut q, mut r);
+            let mut m = last - 2;
+            loop {
+                let z = a[m][m];
+                r = x - z;
+                let s = y - z;
+                p = (r * s - w) / a[m + 1][m] + a[m][m + 1];
+                q = a[m + 1][m + 1] - z - r - s;
+                r = a[m + 2][m + 1];
+                let s = p.abs() + q.abs() + r.abs();
+                p = p / s;
+                q = q / s;
+                r = r / s;
+                if m == l {
+                    break;
+                }
+                let u = a[m][m - 1].abs() * (q.abs() + r.abs());
+                let v = p.abs() * (a[m - 1][m - 1].abs() + z.abs() + a[m + 1][m + 1].abs());
+                if u <= F::epsilon() * v {
+                    break;
+                }
+                m -= 1;
+            }
+
+            for i in m..last - 1 {
+                a[i + 2][i] = F::zero();
+                if i != m {
+                    a[i + 2][i - 1] = F::zero();
+                }
+            }
+
+            // Double QR step on rows l..=last and columns m..=last
+            for k in m..last {
+                if k != m {
+                    p = a[k][k - 1];
+                    q = a[k + 1][k - 1];
+                    r = if k + 1 != last { a[k + 2][k - 1] } else { F::zero() };
+                    x = p.abs() + q.abs() + r.abs();
+                    if x != F::zero() {
+                        p = p / x;
+                        q = q / x;
+                        r = r / x;
+                    }
+                }
+                let s = sign((p * p + q * q + r * r).sqrt(), p);
+                if s != F::zero() {
+                    if k == m {
+                        if l != m {
+                            a[k][k - 1] = -a[k][k - 1];
+                        }
+                    } else {
+                        a[k][k - 1] = -s * x;
+                    }
+                    p = p + s;
+                    x = p / s;
+                    y = q / s;
+                    let z = r / s;
+                    q = q / p;
+                    r = r / p;
+                    let (upper, lower) = a.split_at_mut(k + 1);
+                    let (next, after) = lower.split_at_mut(1);
+                    let columns = upper[k][k..=last].iter_mut().zip(next[0][k..=last].iter_mut());
+                    if k + 1 != last {
+                        for ((a_k, a_k1), a_k2) in columns.zip(after[0][k..=last].iter_mut()) {
+                            let p = *a_k + q * *a_k1 + r * *a_k2;
+                            *a_k2 = *a_k2 - p * z;
+                            *a_k1 = *a_k1 - p * y;
+                            *a_k = *a_k - p * x;
+                        }
+                    } else {
+                        for (a_k, a_k1) in columns {
+                            let p = *a_k + q * *a_k1;
+                            *a_k1 = *a_k1 - p * y;
+                            *a_k = *a_k - p * x;
+                        }
+                    }
+                    let mmin = if last < k + 3 { last } else { k + 3 };
+                    for row in a[l..=mmin].iter_mut() {
+                        let mut p = x * row[k] + y * row[k + 1];
+                        if k + 1 != last {
+                            p = p + z * row[k + 2];
+                            row[k + 2] = row[k + 2] - p * r;
+                        }
+                        row[k + 1] = row[k + 1] - p * q;
+                        row[k] = row[k] - p;
+                    }
+                }
+            }
+        }
+    }
+    Ok(eigenvalues)
+}
+
+/// Finds all complex roots of a polynomial of any degree as eigenvalues of its companion matrix.
+///
+/// Coefficients start from the highest degree. The companion matrix is balanced,
+/// then its eigenvalues are found by the Francis double-shift QR algorithm.
+/// Multiple roots are returned as many times as their multiplicity,
+/// possibly with small imaginary parts.
+///
+/// # Failures
+/// ## NoConvergency
+/// The QR algorithm cannot find an eigenvalue within 30 iterations.
+///
+/// # Examples
+///
+/// ```
+/// use roots::find_roots_eigen_complex;
+///
+/// // x^3 - 1 = 0
+/// let roots = find_roots_eigen_complex(&[1f64, 0f64, 0f64, -1f64]).unwrap();
+/// // Returns 1 and -0.5 ± 0.866i
+/// assert_eq!(roots.len(), 3);
+/// ```
+pub fn find_roots_eigen_complex<F: FloatType>(coefficients: &[F]) -> Result<Vec<Complex<F>>, SearchError> {
+    let a = super::trim(coefficients);
+    if a.len() < 2 {
+        return Ok(Vec::new());
+    }
+
+    // Zero roots are known exactly
+    let zero_roots = a.iter().rev().take_while(|&&x| x == F::zero()).count();
+    let a = &a[..a.len() - zero_roots];
+    let n = a.len() - 1;
+
+    let mut companion = vec![vec![F::zero(); n]; n];
+    for (j, &a_j) in a[1..].iter().enumerate() {
+        companion[0][j] = -a_j / a[0];
+    }
+    for (i, row) in companion.iter_mut().enumerate().skip(1) {
+        row[i - 1] = F::one();
+    }
+
+    balance(&mut companion);
+    let mut roots = hessenberg_eigenvalues(&mut companion)?;
+    roots.extend((0..zero_roots).map(|_| Complex::zero()));
+    Ok(roots)
+}
+
+/// Finds real roots of a polynomial of any degree as eigenvalues of its companion matrix.
+///
+/// Coefficients start from the highest degree. Returned roots are ordered.
+/// Multiple roots may be returned several times, or lost if the QR algorithm
+/// turns them into a complex pair; use find_roots_eigen_complex to see all of them.
+///
+/// # Failures
+/// ## NoConvergency
+/// The QR algorithm cannot find an eigenvalue within 30 iterations.
+///
+/// # Examples
+///
+/// ```
+/// use roots::find_roots_eigen;
+///
+/// // (x^2 + 1)(x - 1)(x - 2)(x - 3) = 0
+/// let roots = find_roots_eigen(&[1f64, -6f64, 12f64, -12f64, 11f64, -6f64]).unwrap();
+/// // Returns [1, 2, 3]
+/// assert_eq!(roots.as_slice().len(), 3);
+/// ```
+pub fn find_roots_eigen<F: FloatType>(coefficients: &[F]) -> Result<RootsVec<F>, SearchError> {
+    let mut roots = RootsVec::new();
+    for root in find_roots_eigen_complex(coefficients)? {
+        if root.im == F::zero() {
+            roots.add_new_root(root.re);
+        }
+    }
+    Ok(roots)
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::super::*;
+
+    #[test]
+    fn test_find_roots_eigen() {
+        assert_eq!(find_roots_eigen(&[1f64]).unwrap().next(), None);
+        assert_eq!(find_roots_eigen(&[0f64, 2f64, -1f64]).unwrap().as_slice(), &[0.5f64]);
+        assert_eq!(find_roots_eigen(&[1f64, 0f64, 1f64]).unwrap().next(), None);
+        assert_float_array_eq!(5e-14, find_roots_eigen(&[1f64, -10f64, 35f64, -50f64, 24f64]).unwrap(), [1f64, 2f64, 3f64, 4f64]);
+        assert_float_array_eq!(5e-14, find_roots_eigen(&[1f64, -6f64, 12f64, -12f64, 11f64, -6f64]).unwrap(), [1f64, 2f64, 3f64]);
+        // x^3 - x with the zero root deflated
+        assert_float_array_eq!(1e-15, find_roots_eigen(&[1f64, 0f64, -1f64, 0f64]).unwrap(), [-1f64, 0f64, 1f64]);
+        assert_float_array_eq!(1e-5, find_roots_eigen(&[2f32, -3f32, -11f32, 6f32]).unwrap(), [-2f32, 0.5f32, 3f32]);
+
+        // Wilkinson-like polynomial (x-1)(x-2)...(x-10)
+        let mut p = Polynomial::new(vec![1f64]);
+        for i in 1..=10 {
+            p = p * Polynomial::new(vec![1f64, -(i as f64)]);
+        }
+        let expected: Vec<f64> = (1..=10).map(|i| i as f64).collect();
+        assert_float_array_eq!(1e-8, find_roots_eigen(p.coefficients()).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_find_roots_eigen_complex() {
+        let roots = find_roots_eigen_complex(&[1f64, 0f64, 0f64, 0f64, -1f64]).unwrap();
+        assert_eq!(roots.len(), 4);
+        for root in roots {
+            assert_float_eq!(1e-14, root.abs(), 1f64);
+            assert_float_eq!(1e-14, (root * root * root * root).re, 1f64);
+        }
+    }
+}
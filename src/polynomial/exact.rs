@@ -0,0 +1,355 @@
+// Copyright (c) 2015, Mikhail Vorotilov
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without
+// modification, are permitted provided that the following conditions are met:
+//
+// * Redistributions of source code must retain the above copyright notice, this
+//   list of conditions and the following disclaimer.
+//
+// * Redistributions in binary form must reproduce the above copyright notice,
+//   this list of conditions and the following disclaimer in the documentation
+//   and/or other materials provided with the distribution.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+// AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+// IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+// FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+// DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+// CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+// OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+// OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Exact arithmetic with integer polynomials.
+//!
+//! Polynomial<i64> keeps integer coefficients; intermediate results use i128
+//! and every operation reports SearchError::Overflow instead of wrapping around.
+//! Rational coefficients are scaled to integers by their common denominator;
+//! they are given as i64 ratios, since arbitrary precision rationals would need a dependency.
+
+use super::super::Convergency;
+use super::super::SearchError;
+use super::dynamic::Polynomial;
+
+use core::cmp::Ordering;
+
+fn gcd(a: i128, b: i128) -> i128 {
+    let (mut a, mut b) = (a.abs(), b.abs());
+    while b != 0 {
+        let r = a % b;
+        a = b;
+        b = r;
+    }
+    a
+}
+
+fn checked(value: Option<i128>) -> Result<i128, SearchError> {
+    value.ok_or(SearchError::Overflow)
+}
+
+/// Removes leading zeros
+fn trim(mut c: Vec<i128>) -> Vec<i128> {
+    let leading_zeros = c.iter().take_while(|&&a| a == 0).count();
+    c.drain(..leading_zeros);
+    c
+}
+
+/// Divides by the content and makes the leading coefficient positive
+fn primitive(c: Vec<i128>) -> Vec<i128> {
+    let c = trim(c);
+    let content = c.iter().fold(0, |acc, &a| gcd(acc, a));
+    if content == 0 {
+        return c;
+    }
+    let content = if c[0] < 0 { -content } else { content };
+    c.into_iter().map(|a| a / content).collect()
+}
+
+/// Pseudo-remainder of a divided by b != 0, made primitive
+fn pseudo_remainder(a: &[i128], b: &[i128]) -> Result<Vec<i128>, SearchError> {
+    let mut r = a.to_vec();
+    while r.len() >= b.len() {
+        let lead = r[0];
+        for (i, r_i) in r.iter_mut().enumerate() {
+            let term = if i < b.len() { checked(lead.checked_mul(b[i]))? } else { 0 };
+            *r_i = checked(checked(r_i.checked_mul(b[0]))?.checked_sub(term))?;
+        }
+        r = primitive(r);
+    }
+    Ok(r)
+}
+
+/// Exact quotient of a divided by b != 0
+fn exact_division(a: &[i128], b: &[i128]) -> Result<Vec<i128>, SearchError> {
+    let mut r = trim(a.to_vec());
+    if r.is_empty() {
+        return Ok(r);
+    }
+    let mut q = Vec::with_capacity(r.len() + 1 - b.len());
+    for i in 0..=r.len() - b.len() {
+        let lead = r[i];
+        // The quotient is exact for b dividing a
+        let factor = lead / b[0];
+        for (r_j, &b_j) in r[i..].iter_mut().zip(b.iter()) {
+            *r_j = checked(r_j.checked_sub(checked(factor.checked_mul(b_j))?))?;
+        }
+        q.push(factor);
+    }
+    Ok(q)
+}
+
+fn derivative(c: &[i128]) -> Result<Vec<i128>, SearchError> {
+    let n = c.len() - 1;
+    c[..n]
+        .iter()
+        .enumerate()
+        .map(|(i, &a)| checked(a.checked_mul((n - i) as i128)))
+        .collect()
+}
+
+fn subtract(a: &[i128], b: &[i128]) -> Result<Vec<i128>, SearchError> {
+    super::zip_aligned(a, b, 0).map(|(a, b)| checked(a.checked_sub(b))).collect()
+}
+
+/// Greatest common divisor by the primitive polynomial remainder sequence, made primitive
+fn polynomial_gcd(a: &[i128], b: &[i128]) -> Result<Vec<i128>, SearchError> {
+    let (mut a, mut b) = (primitive(a.to_vec()), primitive(b.to_vec()));
+    if a.len() < b.len() {
+        core::mem::swap(&mut a, &mut b);
+    }
+    while !b.is_empty() {
+        let r = pseudo_remainder(&a, &b)?;
+        a = b;
+        b = r;
+    }
+    Ok(a)
+}
+
+impl Polynomial<i64> {
+    /// Creates a polynomial with integer coefficients starting from the highest degree
+    pub fn from_integers(coefficients: Vec<i64>) -> Self {
+        let leading_zeros = coefficients.iter().take_while(|&&a| a == 0).count();
+        let mut coefficients = coefficients;
+        coefficients.drain(..leading_zeros);
+        if coefficients.is_empty() {
+            coefficients.push(0);
+        }
+        Self { coefficients }
+    }
+
+    /// Creates a polynomial with the same roots as the one with rational coefficients
+    /// given as (numerator, denominator) pairs, by multiplying it by the common denominator.
+    ///
+    /// Numerators and denominators are limited to i64; arbitrary precision rationals
+    /// (like BigRational of the num crate) are not supported, since the crate has no dependencies.
+    ///
+    /// # Failures
+    /// ## ZeroDenominator
+    /// A denominator is zero.
+    /// ## Overflow
+    /// The common denominator, or a scaled coefficient, does not fit in i64.
+    pub fn from_ratios(ratios: &[(i64, i64)]) -> Result<Self, SearchError> {
+        if ratios.iter().any(|&(_, d)| d == 0) {
+            return Err(SearchError::ZeroDenominator);
+        }
+        let denominator = ratios
+            .iter()
+            .try_fold(1i128, |acc, &(_, d)| checked(acc.checked_mul(d as i128 / gcd(acc, d as i128))))?;
+        let coefficients: Result<Vec<i128>, SearchError> = ratios
+            .iter()
+            .map(|&(n, d)| checked((n as i128).checked_mul(denominator / d as i128)))
+            .collect();
+        Self::from_wide(primitive(coefficients?))
+    }
+
+    fn wide(&self) -> Vec<i128> {
+        trim(self.coefficients.iter().map(|&a| a as i128).collect())
+    }
+
+    fn from_wide(c: Vec<i128>) -> Result<Self, SearchError> {
+        let coefficients: Result<Vec<i64>, SearchError> =
+            c.into_iter().map(|a| if a.abs() <= i64::MAX as i128 { Ok(a as i64) } else { Err(SearchError::Overflow) }).collect();
+        Ok(Self::from_integers(coefficients?))
+    }
+
+    /// The polynomial divided by the GCD of its coefficients, with a positive leading coefficient
+    pub fn primitive_part(&self) -> Self {
+        Self::from_integers(primitive(self.wide()).into_iter().map(|a| a as i64).collect())
+    }
+
+    /// The exact first derivative
+    pub fn exact_derivative(&self) -> Result<Self, SearchError> {
+        Self::from_wide(derivative(&self.wide())?)
+    }
+
+    /// Primitive greatest common divisor, the polynomial of the highest degree dividing both
+    pub fn gcd(&self, other: &Self) -> Result<Self, SearchError> {
+        Self::from_wide(polynomial_gcd(&self.wide(), &other.wide())?)
+    }
+
+    /// Yun's square-free factorization: pairwise coprime primitive polynomials without multiple roots
+    /// with their multiplicities, so that the product of factor^multiplicity equals the polynomial
+    /// up to a constant. Constant factors are omitted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use roots::Polynomial;
+    ///
+    /// // (x - 1)^2 (x + 2)^3 = x^5 + 4x^4 + x^3 - 10x^2 - 4x + 8
+    /// let p = Polynomial::from_integers(vec![1, 4, 1, -10, -4, 8]);
+    /// let factors = p.square_free_factorization().unwrap();
+    /// assert_eq!(factors.len(), 2);
+    /// assert_eq!((factors[0].0.coefficients(), factors[0].1), (&[1i64, -1i64][..], 2));
+    /// assert_eq!((factors[1].0.coefficients(), factors[1].1), (&[1i64, 2i64][..], 3));
+    /// ```
+    pub fn square_free_factorization(&self) -> Result<Vec<(Self, usize)>, SearchError> {
+        let mut factors = Vec::new();
+        let f = self.wide();
+        if f.len() < 2 {
+            return Ok(factors);
+        }
+
+        let df = derivative(&f)?;
+        let a = polynomial_gcd(&f, &df)?;
+        let mut b = exact_division(&f, &a)?;
+        let c = exact_division(&df, &a)?;
+        let mut d = subtract(&c, &derivative(&b)?)?;
+        let mut multiplicity = 1;
+        while b.len() > 1 {
+            let a = polynomial_gcd(&b, &d)?;
+            let c = exact_division(&d, &a)?;
+            b = exact_division(&b, &a)?;
+            d = subtract(&c, &derivative(&b)?)?;
+            if a.len() > 1 {
+                factors.push((Self::from_wide(a)?, multiplicity));
+            }
+            multiplicity += 1;
+        }
+        Ok(factors)
+    }
+
+    /// Converts coefficients to f64
+    pub fn to_f64(&self) -> Polynomial<f64> {
+        Polynomial::new(self.coefficients.iter().map(|&a| a as f64).collect())
+    }
+}
+
+/// Finds real roots of a polynomial with integer coefficients together with their exact multiplicities.
+///
+/// The polynomial is split into square-free factors exactly, and only the roots of every factor
+/// are found in f64: by the analytical solvers up to degree 4, and by find_roots_vca above.
+/// Roots are returned in ascending order as (root, multiplicity).
+///
+/// # Failures
+/// ## Overflow
+/// Coefficients of intermediate polynomials do not fit in i128, or the factors do not fit in i64.
+/// ## NoConvergency
+/// A root of a factor of degree above 4 could not be refined.
+///
+/// # Examples
+///
+/// ```
+/// use roots::find_roots_exact;
+/// use roots::SimpleConvergency;
+///
+/// // (x - 1)^3 (x + 1) = x^4 - 2x^3 + 2x - 1
+/// let mut convergency = SimpleConvergency { eps: 1e-15f64, max_iter: 100 };
+/// let roots = find_roots_exact(&[1, -2, 0, 2, -1], &mut convergency).unwrap();
+/// assert_eq!(roots, vec![(-1f64, 1), (1f64, 3)]);
+/// ```
+pub fn find_roots_exact<C: Convergency<f64>>(coefficients: &[i64], convergency: &mut C) -> Result<Vec<(f64, usize)>, SearchError> {
+    let p = Polynomial::from_integers(coefficients.to_vec());
+    let mut roots = Vec::new();
+    for (factor, multiplicity) in p.square_free_factorization()? {
+        let factor = factor.to_f64();
+        match super::find_roots_analytical(factor.coefficients()) {
+            Some(factor_roots) => roots.extend(factor_roots.map(|x| (x, multiplicity))),
+            None => roots.extend(super::vca::find_roots_vca(factor.coefficients(), convergency)?.map(|x| (x, multiplicity))),
+        }
+    }
+    roots.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(Ordering::Equal));
+    Ok(roots)
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::super::*;
+
+    #[test]
+    fn test_gcd() {
+        // (x - 1)(x + 2) and (x - 1)(2x + 3)
+        let p = Polynomial::from_integers(vec![1, 1, -2]);
+        let q = Polynomial::from_integers(vec![2, 1, -3]);
+        assert_eq!(p.gcd(&q).unwrap().coefficients(), &[1, -1]);
+        assert_eq!(p.gcd(&Polynomial::from_integers(vec![0])).unwrap().coefficients(), &[1, 1, -2]);
+        assert_eq!(p.gcd(&Polynomial::from_integers(vec![1, 5])).unwrap().coefficients(), &[1]);
+        assert_eq!(Polynomial::from_integers(vec![-4, 6]).primitive_part().coefficients(), &[2, -3]);
+        assert_eq!(p.exact_derivative().unwrap().coefficients(), &[2, 1]);
+    }
+
+    #[test]
+    fn test_from_ratios() {
+        // x^2 / 2 - x / 3 + 1 / 6
+        let p = Polynomial::from_ratios(&[(1, 2), (-1, 3), (1, 6)]).unwrap();
+        assert_eq!(p.coefficients(), &[3, -2, 1]);
+        assert_eq!(Polynomial::from_ratios(&[(1, 0), (1, 2)]), Err(SearchError::ZeroDenominator));
+    }
+
+    #[test]
+    fn test_square_free_factorization() {
+        // (x^2 + 1) (x - 2)^2 (3x + 1)^4
+        let p = [&[1i64, 0, 1][..], &[1, -2], &[1, -2], &[3, 1], &[3, 1], &[3, 1], &[3, 1]]
+            .iter()
+            .fold(Polynomial::new(vec![1f64]), |acc, &c| {
+                acc * Polynomial::new(c.iter().map(|&a| a as f64).collect())
+            });
+        let p = Polynomial::from_integers(p.coefficients().iter().map(|&a| a as i64).collect());
+        let factors = p.square_free_factorization().unwrap();
+        assert_eq!(factors.len(), 3);
+        assert_eq!((factors[0].0.coefficients(), factors[0].1), (&[1i64, 0, 1][..], 1));
+        assert_eq!((factors[1].0.coefficients(), factors[1].1), (&[1i64, -2][..], 2));
+        assert_eq!((factors[2].0.coefficients(), factors[2].1), (&[3i64, 1][..], 4));
+
+        assert!(Polynomial::from_integers(vec![5]).square_free_factorization().unwrap().is_empty());
+        assert_eq!(
+            Polynomial::from_integers(vec![i64::MAX, i64::MIN, i64::MAX, 3]).square_free_factorization(),
+            Err(SearchError::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_find_roots_exact() {
+        let mut convergency = SimpleConvergency { eps: 1e-15f64, max_iter: 100 };
+        // (x - 1/3)^3 (x^2 - 2): the analytical cubic solver loses digits on the triple root
+        let roots = find_roots_exact(&[27, -27, -45, 53, -18, 2], &mut convergency).unwrap();
+        assert_eq!(roots.len(), 3);
+        assert_float_eq!(1e-15, roots[0].0, -2f64.sqrt());
+        assert_eq!(roots[0].1, 1);
+        assert_float_eq!(1e-15, roots[1].0, 1f64 / 3f64);
+        assert_eq!(roots[1].1, 3);
+        assert_float_eq!(1e-15, roots[2].0, 2f64.sqrt());
+        assert_eq!(roots[2].1, 1);
+
+        // x^2 (x - 1)^5 (x^5 - x - 1): factors of degree above 4 are solved numerically
+        let p = [&[1i64, 0][..], &[1, 0], &[1, -1], &[1, -1], &[1, -1], &[1, -1], &[1, -1], &[1, 0, 0, 0, -1, -1]]
+            .iter()
+            .fold(Polynomial::new(vec![1f64]), |acc, &c| {
+                acc * Polynomial::new(c.iter().map(|&a| a as f64).collect())
+            });
+        let coefficients: Vec<i64> = p.coefficients().iter().map(|&a| a as i64).collect();
+        let roots = find_roots_exact(&coefficients, &mut convergency).unwrap();
+        assert_eq!(roots.len(), 3);
+        assert_eq!(roots[0], (0f64, 2));
+        assert_eq!(roots[1], (1f64, 5));
+        assert_float_eq!(1e-15, roots[2].0, 1.1673039782614187f64);
+        assert_eq!(roots[2].1, 1);
+
+        // (x - 1)(10x - 11)(x + 5)(x + 7)(x + 9): roots at midpoints of the VCA bisection and next to them
+        let roots = find_roots_exact(&[10, 189, 1000, 378, -5042, 3465], &mut convergency).unwrap();
+        assert_float_array_eq!(1e-14, roots.iter().map(|&(x, _)| x), [-9f64, -7f64, -5f64, 1f64, 1.1f64]);
+        assert!(roots.iter().all(|&(_, multiplicity)| multiplicity == 1));
+    }
+}
@@ -0,0 +1,236 @@
+// Copyright (c) 2015, Mikhail Vorotilov
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without
+// modification, are permitted provided that the following conditions are met:
+//
+// * Redistributions of source code must retain the above copyright notice, this
+//   list of conditions and the following disclaimer.
+//
+// * Redistributions in binary form must reproduce the above copyright notice,
+//   this list of conditions and the following disclaimer in the documentation
+//   and/or other materials provided with the distribution.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+// AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+// IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+// FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+// DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+// CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+// OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+// OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use super::super::FloatType;
+use super::super::Roots;
+use super::sign;
+
+use core::cmp::Ordering;
+
+/// Kind of a critical point, where the derivative is zero
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CriticalPointKind {
+    /// Local minimum: the derivative changes its sign from negative to positive
+    Minimum,
+    /// Local maximum: the derivative changes its sign from positive to negative
+    Maximum,
+    /// Stationary inflection point: the derivative does not change its sign
+    Inflection,
+}
+
+/// Critical point x of a polynomial with the value p(x)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CriticalPoint<F: FloatType> {
+    /// Root of the derivative
+    pub x: F,
+    /// Value of the polynomial at x
+    pub value: F,
+    /// Whether x is a minimum, a maximum or an inflection point
+    pub kind: CriticalPointKind,
+}
+
+/// Critical points of a polynomial and, on an interval, its global extrema
+#[derive(Debug, Clone, PartialEq)]
+pub struct Extrema<F: FloatType> {
+    /// Critical points in ascending order, within the interval if it is given
+    pub critical_points: Vec<CriticalPoint<F>>,
+    /// The global minimum (x, p(x)) on the interval, including its ends
+    pub min: Option<(F, F)>,
+    /// The global maximum (x, p(x)) on the interval, including its ends
+    pub max: Option<(F, F)>,
+}
+
+/// Classifies roots of the derivative by signs of the derivative between them
+fn find_extrema<F: FloatType>(coefficients: &[F], derivative_roots: Roots<F>, interval: Option<(F, F)>) -> Extrema<F> {
+    let n = coefficients.len() - 1;
+    let derivative: Vec<F> = coefficients[..n]
+        .iter()
+        .enumerate()
+        .map(|(i, &a)| a * F::from((n - i) as i16))
+        .collect();
+    let derivative = super::trim(&derivative);
+
+    let roots: Vec<F> = if derivative.is_empty() { Vec::new() } else { derivative_roots.collect() };
+    // Approximations of a multiple root of the derivative have no sign change between them
+    let separate: Vec<F> = super::separate_roots(derivative, roots).into_iter().map(|(x, _)| x).collect();
+
+    let mut critical_points = Vec::with_capacity(separate.len());
+    for (i, &x) in separate.iter().enumerate() {
+        let left = if i == 0 { x - F::one() - x.abs() } else { (separate[i - 1] + x) / F::two() };
+        let right = separate.get(i + 1).map_or(x + F::one() + x.abs(), |&next| (x + next) / F::two());
+        let kind = match (sign(derivative, left), sign(derivative, right)) {
+            (Ordering::Less, Ordering::Greater) => CriticalPointKind::Minimum,
+            (Ordering::Greater, Ordering::Less) => CriticalPointKind::Maximum,
+            _ => CriticalPointKind::Inflection,
+        };
+        critical_points.push(CriticalPoint {
+            x,
+            value: super::eval(coefficients, x),
+            kind,
+        });
+    }
+
+    let (min, max) = match interval {
+        Some((a, b)) => {
+            critical_points.retain(|point| point.x >= a && point.x <= b);
+            let candidates = [(a, super::eval(coefficients, a)), (b, super::eval(coefficients, b))];
+            let mut min = candidates[0];
+            let mut max = candidates[0];
+            for candidate in candidates.iter().cloned().chain(critical_points.iter().map(|point| (point.x, point.value))) {
+                if candidate.1 < min.1 {
+                    min = candidate;
+                }
+                if candidate.1 > max.1 {
+                    max = candidate;
+                }
+            }
+            (Some(min), Some(max))
+        }
+        None => (None, None),
+    };
+
+    Extrema { critical_points, min, max }
+}
+
+/// Finds critical points of a3*x^3 + a2*x^2 + a1*x + a0 as roots of the derivative by find_roots_quadratic.
+///
+/// If the interval [a, b] is given, only critical points within it are returned,
+/// together with the global minimum and maximum on [a, b], ends included.
+///
+/// # Examples
+///
+/// ```
+/// use roots::{find_extrema_cubic, CriticalPointKind};
+///
+/// // x^3 - 3x has a maximum at -1 and a minimum at 1
+/// let extrema = find_extrema_cubic(1f64, 0f64, -3f64, 0f64, Some((-3f64, 2f64)));
+/// assert_eq!(extrema.critical_points[0].kind, CriticalPointKind::Maximum);
+/// assert_eq!(extrema.critical_points[1].kind, CriticalPointKind::Minimum);
+/// // The global minimum is at the left end, and the maximum is reached at both -1 and 2
+/// assert_eq!(extrema.min, Some((-3f64, -18f64)));
+/// assert_eq!(extrema.max.unwrap().1, 2f64);
+/// ```
+pub fn find_extrema_cubic<F: FloatType>(a3: F, a2: F, a1: F, a0: F, interval: Option<(F, F)>) -> Extrema<F> {
+    let roots = super::super::find_roots_quadratic(F::three() * a3, F::two() * a2, a1);
+    find_extrema(&[a3, a2, a1, a0], roots, interval)
+}
+
+/// Finds critical points of a4*x^4 + ... + a0 as roots of the derivative by find_roots_cubic,
+/// like find_extrema_cubic
+pub fn find_extrema_quartic<F: FloatType>(a4: F, a3: F, a2: F, a1: F, a0: F, interval: Option<(F, F)>) -> Extrema<F> {
+    let roots = super::super::find_roots_cubic(F::four() * a4, F::three() * a3, F::two() * a2, a1);
+    find_extrema(&[a4, a3, a2, a1, a0], roots, interval)
+}
+
+/// Finds critical points of a5*x^5 + ... + a0 as roots of the derivative by find_roots_quartic,
+/// like find_extrema_cubic
+pub fn find_extrema_quintic<F: FloatType>(a5: F, a4: F, a3: F, a2: F, a1: F, a0: F, interval: Option<(F, F)>) -> Extrema<F> {
+    let roots = super::super::find_roots_quartic(F::five() * a5, F::four() * a4, F::three() * a3, F::two() * a2, a1);
+    find_extrema(&[a5, a4, a3, a2, a1, a0], roots, interval)
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::super::*;
+
+    #[test]
+    fn test_find_extrema_cubic() {
+        let extrema = find_extrema_cubic(1f64, 0f64, -3f64, 0f64, None);
+        assert_eq!(
+            extrema.critical_points,
+            vec![
+                CriticalPoint {
+                    x: -1f64,
+                    value: 2f64,
+                    kind: CriticalPointKind::Maximum
+                },
+                CriticalPoint {
+                    x: 1f64,
+                    value: -2f64,
+                    kind: CriticalPointKind::Minimum
+                },
+            ]
+        );
+        assert_eq!((extrema.min, extrema.max), (None, None));
+
+        // x^3 has a stationary inflection point, and x^3 + x has no critical points
+        let extrema = find_extrema_cubic(1f64, 0f64, 0f64, 0f64, None);
+        assert_eq!(extrema.critical_points.len(), 1);
+        assert_eq!(extrema.critical_points[0].kind, CriticalPointKind::Inflection);
+        assert!(find_extrema_cubic(1f64, 0f64, 1f64, 0f64, None).critical_points.is_empty());
+
+        // A parabola and a constant
+        let extrema = find_extrema_cubic(0f32, -1f32, 2f32, 0f32, Some((0f32, 3f32)));
+        assert_eq!(extrema.critical_points[0].kind, CriticalPointKind::Maximum);
+        assert_eq!(extrema.max, Some((1f32, 1f32)));
+        assert_eq!(extrema.min, Some((3f32, -3f32)));
+        let extrema = find_extrema_cubic(0f32, 0f32, 0f32, 5f32, Some((0f32, 3f32)));
+        assert!(extrema.critical_points.is_empty());
+        assert_eq!(extrema.min.unwrap().1, 5f32);
+    }
+
+    #[test]
+    fn test_find_extrema_quartic() {
+        // (x^2 - 1)^2 = x^4 - 2x^2 + 1: minima at -1 and 1, a maximum at 0
+        let extrema = find_extrema_quartic(1f64, 0f64, -2f64, 0f64, 1f64, Some((-0.5f64, 2f64)));
+        assert_eq!(extrema.critical_points.len(), 2);
+        assert_eq!(extrema.critical_points[0].kind, CriticalPointKind::Maximum);
+        assert_eq!(extrema.critical_points[1].kind, CriticalPointKind::Minimum);
+        let min = extrema.min.unwrap();
+        assert_float_eq!(1e-15, min.0, 1f64);
+        assert_float_eq!(1e-15, min.1, 0f64);
+        assert_eq!(extrema.max, Some((2f64, 9f64)));
+
+        // (x - 1)^4 has a minimum where the derivative has a triple root
+        let extrema = find_extrema_quartic(1f64, -4f64, 6f64, -4f64, 1f64, None);
+        assert_eq!(extrema.critical_points.len(), 1);
+        assert_float_eq!(1e-5, extrema.critical_points[0].x, 1f64);
+        assert_eq!(extrema.critical_points[0].kind, CriticalPointKind::Minimum);
+    }
+
+    #[test]
+    fn test_find_extrema_quintic() {
+        // x^5 - 5x^3 + 4x with p'(x) = 5x^4 - 15x^2 + 4
+        let extrema = find_extrema_quintic(1f64, 0f64, -5f64, 0f64, 4f64, 0f64, None);
+        let kinds: Vec<CriticalPointKind> = extrema.critical_points.iter().map(|point| point.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                CriticalPointKind::Maximum,
+                CriticalPointKind::Minimum,
+                CriticalPointKind::Maximum,
+                CriticalPointKind::Minimum
+            ]
+        );
+        let x = ((15f64 + 145f64.sqrt()) / 10f64).sqrt();
+        assert_float_eq!(1e-15, extrema.critical_points[3].x, x);
+
+        // x^5 is monotonic with a stationary inflection point at 0
+        let extrema = find_extrema_quintic(1f64, 0f64, 0f64, 0f64, 0f64, 0f64, Some((-1f64, 1f64)));
+        assert_eq!(extrema.critical_points.len(), 1);
+        assert_eq!(extrema.critical_points[0].kind, CriticalPointKind::Inflection);
+        assert_eq!(extrema.min, Some((-1f64, -1f64)));
+        assert_eq!(extrema.max, Some((1f64, 1f64)));
+    }
+}
@@ -0,0 +1,193 @@
+// Copyright (c) 2015, Mikhail Vorotilov
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without
+// modification, are permitted provided that the following conditions are met:
+//
+// * Redistributions of source code must retain the above copyright notice, this
+//   list of conditions and the following disclaimer.
+//
+// * Redistributions in binary form must reproduce the above copyright notice,
+//   this list of conditions and the following disclaimer in the documentation
+//   and/or other materials provided with the distribution.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+// AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+// IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+// FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+// DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+// CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+// OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+// OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use super::super::FloatType;
+use super::super::Roots;
+use super::dynamic::Polynomial;
+
+use core::ops::Add;
+use core::ops::Mul;
+use core::ops::Neg;
+use core::ops::Sub;
+
+/// Polynomial with N coefficients stored on the stack.
+///
+/// Coefficients start from the highest degree, so the degree is at most N-1.
+/// Addition, subtraction and the derivative keep the number of coefficients; the derivative
+/// has a leading zero coefficient. Multiplication of polynomials and the antiderivative
+/// increase the degree, so they return Polynomial.
+///
+/// # Examples
+///
+/// ```
+/// use roots::FixedPolynomial;
+///
+/// // x^3 - x
+/// let p = FixedPolynomial::new([1f64, 0f64, -1f64, 0f64]);
+/// assert_eq!(p.eval(2f64), 6f64);
+/// assert_eq!(p.derivative().coefficients(), &[0f64, 3f64, 0f64, -1f64]);
+/// assert_eq!((p * FixedPolynomial::new([1f64, 1f64])).coefficients(), &[1f64, 1f64, -1f64, -1f64, 0f64]);
+///
+/// let roots = p.roots();
+/// // Returns [-1, 0, 1]
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FixedPolynomial<F: FloatType, const N: usize> {
+    coefficients: [F; N],
+}
+
+impl<F: FloatType, const N: usize> FixedPolynomial<F, N> {
+    /// Creates a polynomial from coefficients starting from the highest degree
+    pub fn new(coefficients: [F; N]) -> Self {
+        Self { coefficients }
+    }
+
+    /// Coefficients starting from the highest degree
+    pub fn coefficients(&self) -> &[F; N] {
+        &self.coefficients
+    }
+
+    /// Degree of the polynomial, ignoring leading zero coefficients
+    pub fn degree(&self) -> usize {
+        super::trim(&self.coefficients).len().max(1) - 1
+    }
+
+    /// Value of the polynomial at x
+    pub fn eval(&self, x: F) -> F {
+        super::eval(&self.coefficients, x)
+    }
+
+    /// Value of the polynomial and its first derivative at x
+    pub fn eval_with_derivative(&self, x: F) -> (F, F) {
+        let mut values = [F::zero(); 2];
+        super::eval_derivatives(&self.coefficients, x, &mut values);
+        (values[0], values[1])
+    }
+
+    /// Values of the polynomial and its derivatives at x, as many as the length of values
+    pub fn eval_derivatives(&self, x: F, values: &mut [F]) {
+        super::eval_derivatives(&self.coefficients, x, values)
+    }
+
+    /// The first derivative, with the leading coefficient set to zero
+    pub fn derivative(&self) -> Self {
+        let mut coefficients = [F::zero(); N];
+        for (i, (d, &a)) in coefficients.iter_mut().skip(1).zip(self.coefficients.iter()).enumerate() {
+            *d = a * F::from((N - 1 - i) as i16);
+        }
+        Self { coefficients }
+    }
+
+    /// The antiderivative with the given value at 0
+    pub fn antiderivative(&self, constant: F) -> Polynomial<F> {
+        Polynomial::from(*self).antiderivative(constant)
+    }
+
+    /// Real roots found by the analytical solvers.
+    ///
+    /// Returns None if the degree is higher than 4.
+    pub fn roots(&self) -> Option<Roots<F>> {
+        super::find_roots_analytical(&self.coefficients)
+    }
+}
+
+impl<F: FloatType, const N: usize> From<FixedPolynomial<F, N>> for Polynomial<F> {
+    fn from(p: FixedPolynomial<F, N>) -> Self {
+        Polynomial::new(p.coefficients.to_vec())
+    }
+}
+
+impl<F: FloatType, const N: usize> Add for FixedPolynomial<F, N> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        let mut coefficients = self.coefficients;
+        for (a, &b) in coefficients.iter_mut().zip(other.coefficients.iter()) {
+            *a = *a + b;
+        }
+        Self { coefficients }
+    }
+}
+
+impl<F: FloatType, const N: usize> Sub for FixedPolynomial<F, N> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self + (-other)
+    }
+}
+
+impl<F: FloatType, const N: usize> Neg for FixedPolynomial<F, N> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        let mut coefficients = self.coefficients;
+        for a in coefficients.iter_mut() {
+            *a = -*a;
+        }
+        Self { coefficients }
+    }
+}
+
+impl<F: FloatType, const N: usize> Mul<F> for FixedPolynomial<F, N> {
+    type Output = Self;
+
+    fn mul(self, factor: F) -> Self {
+        let mut coefficients = self.coefficients;
+        for a in coefficients.iter_mut() {
+            *a = *a * factor;
+        }
+        Self { coefficients }
+    }
+}
+
+impl<F: FloatType, const N: usize, const M: usize> Mul<FixedPolynomial<F, M>> for FixedPolynomial<F, N> {
+    type Output = Polynomial<F>;
+
+    fn mul(self, other: FixedPolynomial<F, M>) -> Polynomial<F> {
+        &Polynomial::from(self) * &Polynomial::from(other)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::super::*;
+
+    #[test]
+    fn test_fixed_polynomial() {
+        let p = FixedPolynomial::new([0f32, 1f32, 0f32, -4f32]);
+        let q = FixedPolynomial::new([1f32, 1f32, 1f32, 1f32]);
+        assert_eq!(p.degree(), 2);
+        assert_eq!(FixedPolynomial::new([0f32; 3]).degree(), 0);
+        assert_eq!((p + q).coefficients(), &[1f32, 2f32, 1f32, -3f32]);
+        assert_eq!((q - q) * 2f32, FixedPolynomial::new([0f32; 4]));
+        assert_eq!(p.derivative().derivative().coefficients(), &[0f32, 0f32, 0f32, 2f32]);
+        assert_eq!(Polynomial::from(p).coefficients(), &[1f32, 0f32, -4f32]);
+        assert_eq!((p * q).coefficients(), &[1f32, 1f32, -3f32, -3f32, -4f32, -4f32]);
+        assert_eq!((p * FixedPolynomial::new([0f32, 0f32])).coefficients(), &[0f32]);
+        assert_eq!(p.antiderivative(1f32).coefficients(), &[1f32 / 3f32, 0f32, -4f32, 1f32]);
+        assert_eq!(q.derivative().antiderivative(1f32), Polynomial::from(q));
+        assert_float_array_eq!(1e-6, p.roots().unwrap(), [-2f32, 2f32]);
+    }
+}
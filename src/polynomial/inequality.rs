@@ -0,0 +1,273 @@
+// Copyright (c) 2015, Mikhail Vorotilov
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without
+// modification, are permitted provided that the following conditions are met:
+//
+// * Redistributions of source code must retain the above copyright notice, this
+//   list of conditions and the following disclaimer.
+//
+// * Redistributions in binary form must reproduce the above copyright notice,
+//   this list of conditions and the following disclaimer in the documentation
+//   and/or other materials provided with the distribution.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+// AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+// IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+// FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+// DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+// CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+// OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+// OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use super::super::FloatType;
+use super::super::SearchError;
+use super::sign;
+
+use core::cmp::Ordering;
+use core::ops::Bound;
+
+/// Relation between the value of a polynomial and zero
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Relation {
+    /// p(x) < 0
+    Less,
+    /// p(x) <= 0
+    LessOrEqual,
+    /// p(x) > 0
+    Greater,
+    /// p(x) >= 0
+    GreaterOrEqual,
+}
+
+impl Relation {
+    /// Whether the relation holds for a value of the given sign
+    fn holds(self, sign: Ordering) -> bool {
+        match self {
+            Relation::Less => sign == Ordering::Less,
+            Relation::LessOrEqual => sign != Ordering::Greater,
+            Relation::Greater => sign == Ordering::Greater,
+            Relation::GreaterOrEqual => sign != Ordering::Less,
+        }
+    }
+}
+
+/// Interval of real numbers; a single point is the interval with both ends included
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Interval<F: FloatType> {
+    /// The lower end
+    pub start: Bound<F>,
+    /// The upper end
+    pub end: Bound<F>,
+}
+
+/// Finds the set of x where p(x) relates to zero as given, as sorted disjoint intervals.
+///
+/// Coefficients start from the highest degree. Real roots are found by the analytical solvers
+/// up to degree 4 and as eigenvalues of the companion matrix for higher degrees, where nearly real
+/// eigenvalues count as real, since the QR algorithm may split a multiple root into a complex pair.
+/// The sign of the polynomial is evaluated between adjacent roots; roots with no sign change between them,
+/// like approximations of a multiple root, are merged. Touching roots of even multiplicity
+/// split intervals for strict relations and appear as single points for non-strict ones.
+///
+/// # Failures
+/// ## NoConvergency
+/// Roots of a polynomial of degree above 4 could not be found.
+///
+/// # Examples
+///
+/// ```
+/// use core::ops::Bound;
+/// use roots::{solve_poly_inequality, Interval, Relation};
+///
+/// // x^2 (x - 2) >= 0 holds at 0 and from 2
+/// let intervals = solve_poly_inequality(&[1f64, -2f64, 0f64, 0f64], Relation::GreaterOrEqual).unwrap();
+/// assert_eq!(intervals, vec![
+///     Interval { start: Bound::Included(0f64), end: Bound::Included(0f64) },
+///     Interval { start: Bound::Included(2f64), end: Bound::Unbounded },
+/// ]);
+/// ```
+pub fn solve_poly_inequality<F: FloatType>(coefficients: &[F], relation: Relation) -> Result<Vec<Interval<F>>, SearchError> {
+    let a = super::trim(coefficients);
+    let roots: Vec<F> = match super::find_roots_analytical(a) {
+        Some(roots) if a.len() > 1 => roots.collect(),
+        Some(_) => Vec::new(),
+        None => super::find_nearly_real_roots_eigen(a)?,
+    };
+
+    // Signs between roots decide which roots are separate
+    let separate: Vec<F> = super::separate_roots(a, roots).into_iter().map(|(root, _)| root).collect();
+    let mut gaps: Vec<Ordering> = separate.windows(2).map(|w| sign(a, (w[0] + w[1]) / F::two())).collect();
+    let outer = |x: F| sign(a, x);
+    let (first_gap, last_gap) = match (separate.first(), separate.last()) {
+        (Some(&first), Some(&last)) => (outer(first - F::one() - first.abs()), outer(last + F::one() + last.abs())),
+        _ => {
+            let sign = if a.is_empty() {
+                Ordering::Equal
+            } else if a[0] > F::zero() {
+                Ordering::Greater
+            } else {
+                Ordering::Less
+            };
+            (sign, sign)
+        }
+    };
+    gaps.insert(0, first_gap);
+    if !separate.is_empty() {
+        gaps.push(last_gap);
+    }
+
+    // Runs of gaps and roots where the relation holds: gap 0, root 0, gap 1, ..., root k-1, gap k
+    let k = separate.len();
+    let mut parts = Vec::with_capacity(2 * k + 1);
+    for (i, &gap) in gaps.iter().enumerate() {
+        let start = if i == 0 { Bound::Unbounded } else { Bound::Excluded(separate[i - 1]) };
+        let end = if i == k { Bound::Unbounded } else { Bound::Excluded(separate[i]) };
+        parts.push((relation.holds(gap), start, end));
+        if i < k {
+            parts.push((relation.holds(Ordering::Equal), Bound::Included(separate[i]), Bound::Included(separate[i])));
+        }
+    }
+
+    let mut intervals = Vec::new();
+    let mut run: Option<(Bound<F>, Bound<F>)> = None;
+    for (holds, start, end) in parts {
+        run = match (holds, run) {
+            (true, Some((run_start, _))) => Some((run_start, end)),
+            (true, None) => Some((start, end)),
+            (false, Some((run_start, run_end))) => {
+                intervals.push(Interval {
+                    start: run_start,
+                    end: run_end,
+                });
+                None
+            }
+            (false, None) => None,
+        };
+    }
+    if let Some((start, end)) = run {
+        intervals.push(Interval { start, end });
+    }
+    Ok(intervals)
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::super::*;
+    use core::ops::Bound;
+    use core::ops::Bound::*;
+
+    fn interval(start: Bound<f64>, end: Bound<f64>) -> Interval<f64> {
+        Interval { start, end }
+    }
+
+    fn assert_bound_eq(precision: f64, given: Bound<f64>, expected: Bound<f64>) {
+        match (given, expected) {
+            (Included(g), Included(e)) | (Excluded(g), Excluded(e)) => assert_float_eq!(precision, g, e),
+            (Unbounded, Unbounded) => {}
+            _ => panic!("{:?} != {:?}", given, expected),
+        }
+    }
+
+    fn assert_intervals_eq(precision: f64, given: Vec<Interval<f64>>, expected: Vec<Interval<f64>>) {
+        assert_eq!(given.len(), expected.len());
+        for (g, e) in given.into_iter().zip(expected) {
+            assert_bound_eq(precision, g.start, e.start);
+            assert_bound_eq(precision, g.end, e.end);
+        }
+    }
+
+    #[test]
+    fn test_solve_poly_inequality() {
+        // x^2 - 1
+        let p = [1f64, 0f64, -1f64];
+        assert_eq!(
+            solve_poly_inequality(&p, Relation::Greater).unwrap(),
+            vec![interval(Unbounded, Excluded(-1f64)), interval(Excluded(1f64), Unbounded)]
+        );
+        assert_eq!(solve_poly_inequality(&p, Relation::LessOrEqual).unwrap(), vec![interval(Included(-1f64), Included(1f64))]);
+        assert_eq!(solve_poly_inequality(&p, Relation::Less).unwrap(), vec![interval(Excluded(-1f64), Excluded(1f64))]);
+
+        // Constants, the zero polynomial and no real roots
+        assert_eq!(solve_poly_inequality(&[0f64, 2f64], Relation::Greater).unwrap(), vec![interval(Unbounded, Unbounded)]);
+        assert!(solve_poly_inequality(&[2f64], Relation::LessOrEqual).unwrap().is_empty());
+        assert_eq!(solve_poly_inequality(&[0f64], Relation::LessOrEqual).unwrap(), vec![interval(Unbounded, Unbounded)]);
+        assert!(solve_poly_inequality::<f64>(&[], Relation::Less).unwrap().is_empty());
+        assert!(solve_poly_inequality(&[1f64, 0f64, 0f64, 0f64, 1f64], Relation::Less).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_solve_poly_inequality_touching() {
+        // (x - 1)^2 touches zero
+        let p = [1f64, -2f64, 1f64];
+        assert_eq!(
+            solve_poly_inequality(&p, Relation::Greater).unwrap(),
+            vec![interval(Unbounded, Excluded(1f64)), interval(Excluded(1f64), Unbounded)]
+        );
+        assert_eq!(solve_poly_inequality(&p, Relation::GreaterOrEqual).unwrap(), vec![interval(Unbounded, Unbounded)]);
+        assert_eq!(solve_poly_inequality(&p, Relation::LessOrEqual).unwrap(), vec![interval(Included(1f64), Included(1f64))]);
+        assert!(solve_poly_inequality(&p, Relation::Less).unwrap().is_empty());
+
+        // (x - 1)^2 (x - 2)
+        let p = [1f64, -4f64, 5f64, -2f64];
+        assert_intervals_eq(
+            1e-15,
+            solve_poly_inequality(&p, Relation::Less).unwrap(),
+            vec![interval(Unbounded, Excluded(1f64)), interval(Excluded(1f64), Excluded(2f64))],
+        );
+        assert_intervals_eq(1e-15, solve_poly_inequality(&p, Relation::LessOrEqual).unwrap(), vec![interval(Unbounded, Included(2f64))]);
+
+        // (x + 1)^3 (x - 3) changes sign at the triple root
+        let p = coefficients_from_roots(&[(Complex::from(-1f64), 3), (Complex::from(3f64), 1)]);
+        assert_intervals_eq(1e-5, solve_poly_inequality(&p, Relation::Less).unwrap(), vec![interval(Excluded(-1f64), Excluded(3f64))]);
+    }
+
+    #[test]
+    fn test_solve_poly_inequality_high_degree() {
+        // (x + 2) x (x - 1) (x - 3) (x - 4)
+        let p = coefficients_from_roots(&[-2f64, 0f64, 1f64, 3f64, 4f64].iter().map(|&r| (Complex::from(r), 1)).collect::<Vec<_>>());
+        assert_intervals_eq(
+            1e-13,
+            solve_poly_inequality(&p, Relation::Greater).unwrap(),
+            vec![
+                interval(Excluded(-2f64), Excluded(0f64)),
+                interval(Excluded(1f64), Excluded(3f64)),
+                interval(Excluded(4f64), Unbounded),
+            ],
+        );
+
+        // Touching roots: (x - 1)^2 (x^2 + 1) (x^2 + 4) and (x - 0.7)^2 (x - 2)^2 (x + 3)
+        let p = coefficients_from_roots(&[(Complex::from(1f64), 2), (Complex::new(0f64, 1f64), 1), (Complex::new(0f64, 2f64), 1)]);
+        assert_intervals_eq(
+            1e-7,
+            solve_poly_inequality(&p, Relation::LessOrEqual).unwrap(),
+            vec![interval(Included(1f64), Included(1f64))],
+        );
+        assert_intervals_eq(
+            1e-7,
+            solve_poly_inequality(&p, Relation::Greater).unwrap(),
+            vec![interval(Unbounded, Excluded(1f64)), interval(Excluded(1f64), Unbounded)],
+        );
+        let p = coefficients_from_roots(&[(Complex::from(0.7f64), 2), (Complex::from(2f64), 2), (Complex::from(-3f64), 1)]);
+        assert_intervals_eq(
+            1e-7,
+            solve_poly_inequality(&p, Relation::LessOrEqual).unwrap(),
+            vec![
+                interval(Unbounded, Included(-3f64)),
+                interval(Included(0.7f64), Included(0.7f64)),
+                interval(Included(2f64), Included(2f64)),
+            ],
+        );
+        assert_intervals_eq(
+            1e-7,
+            solve_poly_inequality(&p, Relation::Greater).unwrap(),
+            vec![
+                interval(Excluded(-3f64), Excluded(0.7f64)),
+                interval(Excluded(0.7f64), Excluded(2f64)),
+                interval(Excluded(2f64), Unbounded),
+            ],
+        );
+    }
+}
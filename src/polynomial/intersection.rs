@@ -0,0 +1,159 @@
+// Copyright (c) 2015, Mikhail Vorotilov
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without
+// modification, are permitted provided that the following conditions are met:
+//
+// * Redistributions of source code must retain the above copyright notice, this
+//   list of conditions and the following disclaimer.
+//
+// * Redistributions in binary form must reproduce the above copyright notice,
+//   this list of conditions and the following disclaimer in the documentation
+//   and/or other materials provided with the distribution.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+// AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+// IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+// FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+// DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+// CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+// OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+// OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use super::super::FloatType;
+use super::super::SearchError;
+
+/// Point where two polynomial curves meet
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Intersection<F: FloatType> {
+    /// Argument of the intersection
+    pub x: F,
+    /// Common value of both polynomials at x
+    pub y: F,
+    /// Multiplicity of x as a root of p - q: 1 for crossing curves, 2 or more for tangential contacts
+    pub multiplicity: usize,
+}
+
+/// Derivatives of p - q at x vanishing within sqrt(epsilon) relative to magnitudes of their terms
+fn vanishing_derivatives<F: FloatType>(difference: &[F], x: F) -> usize {
+    let tolerance = F::epsilon().sqrt();
+    let mut derivative = difference.to_vec();
+    let mut count = 0;
+    while derivative.len() > 1 {
+        let n = derivative.len() - 1;
+        derivative = derivative[..n]
+            .iter()
+            .enumerate()
+            .map(|(i, &a)| a * F::from((n - i) as i16))
+            .collect();
+        let value = super::eval(&derivative, x).abs();
+        let scale = derivative.iter().fold(F::zero(), |acc, &a| acc * x.abs() + a.abs());
+        if value > tolerance * scale {
+            break;
+        }
+        count += 1;
+    }
+    count
+}
+
+/// Finds all real x where the polynomials p and q intersect, with values and multiplicities.
+///
+/// Coefficients of both polynomials start from the highest degree; their lengths may differ.
+/// Roots of p - q are found by the analytical solvers up to degree 4 and as eigenvalues
+/// of the companion matrix for higher degrees, where nearly real eigenvalues count as real.
+/// Approximations of a multiple root are merged, and the multiplicity also counts derivatives
+/// of p - q vanishing at the root.
+/// Intersections are returned in ascending order; identical polynomials have none.
+///
+/// # Failures
+/// ## NoConvergency
+/// Roots of p - q of degree above 4 could not be found.
+///
+/// # Examples
+///
+/// ```
+/// use roots::find_intersections;
+///
+/// // The parabola x^2 touches the line 2x - 1 at (1, 1) and crosses the line x + 2 at -1 and 2
+/// let touching = find_intersections(&[1f64, 0f64, 0f64], &[2f64, -1f64]).unwrap();
+/// assert_eq!((touching[0].x, touching[0].y, touching[0].multiplicity), (1f64, 1f64, 2));
+///
+/// let crossing = find_intersections(&[1f64, 0f64, 0f64], &[1f64, 2f64]).unwrap();
+/// assert_eq!(crossing.len(), 2);
+/// assert_eq!(crossing[1].multiplicity, 1);
+/// ```
+pub fn find_intersections<F: FloatType>(p: &[F], q: &[F]) -> Result<Vec<Intersection<F>>, SearchError> {
+    let difference = super::subtract(p, q);
+    let d = super::trim(&difference);
+    if d.len() < 2 {
+        return Ok(Vec::new());
+    }
+
+    let roots: Vec<F> = match super::find_roots_analytical(d) {
+        Some(roots) => roots.collect(),
+        None => super::find_nearly_real_roots_eigen(d)?,
+    };
+
+    Ok(super::separate_roots(d, roots)
+        .into_iter()
+        .map(|(x, count)| Intersection {
+            x,
+            y: super::eval(p, x),
+            multiplicity: count.max(1 + vanishing_derivatives(d, x)),
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::super::*;
+
+    #[test]
+    fn test_find_intersections() {
+        // Identical and parallel curves
+        assert!(find_intersections(&[1f64, 2f64], &[0f64, 1f64, 2f64]).unwrap().is_empty());
+        assert!(find_intersections(&[1f64, 2f64], &[1f64, 3f64]).unwrap().is_empty());
+
+        // x^3 crosses x at -1, 0 and 1
+        let intersections = find_intersections(&[1f64, 0f64, 0f64, 0f64], &[1f64, 0f64]).unwrap();
+        assert_eq!(intersections.len(), 3);
+        for (intersection, &x) in intersections.iter().zip([-1f64, 0f64, 1f64].iter()) {
+            assert_float_eq!(1e-15, intersection.x, x);
+            assert_float_eq!(1e-15, intersection.y, x);
+            assert_eq!(intersection.multiplicity, 1);
+        }
+
+        // x^3 meets the tangent 3x - 2 at 1 and crosses it at -2
+        let intersections = find_intersections(&[1f32, 0f32, 0f32, 0f32], &[3f32, -2f32]).unwrap();
+        assert_eq!(intersections.len(), 2);
+        assert_float_eq!(1e-6, intersections[0].x, -2f32);
+        assert_float_eq!(1e-5, intersections[0].y, -8f32);
+        assert_eq!(intersections[0].multiplicity, 1);
+        assert_float_eq!(1e-3, intersections[1].x, 1f32);
+        assert_eq!(intersections[1].multiplicity, 2);
+    }
+
+    #[test]
+    fn test_find_intersections_high_degree() {
+        // p - q = (x + 1)^2 (x - 1) (x - 2) (x - 3) (x^2 + 1)
+        let d = coefficients_from_roots(&[
+            (Complex::from(-1f64), 2),
+            (Complex::from(1f64), 1),
+            (Complex::from(2f64), 1),
+            (Complex::from(3f64), 1),
+            (Complex::new(0f64, 1f64), 1),
+        ]);
+        let q = [2f64, 0f64, -1f64];
+        let p: Vec<f64> = polynomial::zip_aligned(&d, &q, 0f64).map(|(a, b)| a + b).collect();
+        let intersections = find_intersections(&p, &q).unwrap();
+        let expected = [(-1f64, 2), (1f64, 1), (2f64, 1), (3f64, 1)];
+        assert_eq!(intersections.len(), expected.len());
+        for (intersection, &(x, multiplicity)) in intersections.iter().zip(expected.iter()) {
+            assert_float_eq!(1e-7, intersection.x, x);
+            assert_float_eq!(1e-6, intersection.y, 2f64 * x * x - 1f64);
+            assert_eq!(intersection.multiplicity, multiplicity);
+        }
+    }
+}
@@ -0,0 +1,251 @@
+// Copyright (c) 2015, Mikhail Vorotilov
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without
+// modification, are permitted provided that the following conditions are met:
+//
+// * Redistributions of source code must retain the above copyright notice, this
+//   list of conditions and the following disclaimer.
+//
+// * Redistributions in binary form must reproduce the above copyright notice,
+//   this list of conditions and the following disclaimer in the documentation
+//   and/or other materials provided with the distribution.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+// AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+// IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+// FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+// DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+// CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+// OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+// OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use super::super::Complex;
+use super::super::Convergency;
+use super::super::FloatType;
+use super::super::SearchError;
+
+use core::cmp::Ordering;
+
+/// Evaluates the polynomial with complex coefficients and its first two derivatives at z
+/// in one pass of the Horner scheme. Returns (p(z), p'(z), p''(z)).
+pub fn eval_with_second_derivative<F: FloatType>(coefficients: &[Complex<F>], z: Complex<F>) -> (Complex<F>, Complex<F>, Complex<F>) {
+    let (p, dp, half_ddp) = coefficients
+        .iter()
+        .fold((Complex::zero(), Complex::zero(), Complex::zero()), |(p, dp, half_ddp), &a| {
+            (p * z + a, dp * z + p, half_ddp * z + dp)
+        });
+    (p, dp, half_ddp * F::two())
+}
+
+/// Finds a root of the polynomial with complex coefficients by Laguerre's method starting from z.
+fn laguerre<F, C>(coefficients: &[Complex<F>], mut z: Complex<F>, convergency: &mut C) -> Result<Complex<F>, SearchError>
+where
+    F: FloatType,
+    C: Convergency<F>,
+{
+    let n = F::from((coefficients.len() - 1) as i16);
+    let mut iter = 0;
+    loop {
+        let (p, dp, ddp) = eval_with_second_derivative(coefficients, z);
+        let p_abs = p.abs();
+        if p_abs <= super::rounding_error(coefficients.iter().map(|a| a.abs()), z.abs()) || convergency.is_root_found(p_abs) {
+            return Ok(z);
+        }
+
+        let g = dp / p;
+        let h = g * g - ddp / p;
+        let sq = ((h * n - g * g) * (n - F::one())).sqrt();
+        let (plus, minus) = (g + sq, g - sq);
+        let denominator = if plus.abs() >= minus.abs() { plus } else { minus };
+        let step = if denominator.abs() > F::zero() {
+            Complex::from(n) / denominator
+        } else {
+            // All derivatives vanish: jump in a direction changing with the iteration
+            let angle = F::from(iter as i16);
+            Complex::new(angle.cos(), angle.sin()) * (F::one() + z.abs())
+        };
+        // Fractional steps once in a while break limit cycles
+        let step = if iter % 10 == 9 {
+            step * (F::from(((iter / 10) % 8 + 1) as i16) / F::from(8i16))
+        } else {
+            step
+        };
+
+        let next = z - step;
+        if next == z || convergency.is_converged(step.abs()) {
+            return Ok(next);
+        }
+        z = next;
+
+        iter += 1;
+        if convergency.is_iteration_limit_reached(iter) {
+            return Err(SearchError::NoConvergency);
+        }
+    }
+}
+
+/// Divides the polynomial by (x - z). Forward deflation starts from the highest degree and is stable
+/// for roots smaller than the other ones; backward deflation starts from the constant term
+/// and is stable for larger roots.
+fn deflate<F: FloatType>(coefficients: &[Complex<F>], z: Complex<F>, forward: bool) -> Vec<Complex<F>> {
+    let m = coefficients.len() - 1;
+    let mut q = vec![Complex::zero(); m];
+    if forward {
+        let mut b = Complex::zero();
+        for (q_k, &a) in q.iter_mut().zip(coefficients.iter()) {
+            b = b * z + a;
+            *q_k = b;
+        }
+    } else {
+        let mut b = Complex::zero();
+        for (q_k, &a) in q.iter_mut().rev().zip(coefficients.iter().rev()) {
+            b = (b - a) / z;
+            *q_k = b;
+        }
+    }
+    q
+}
+
+/// Finds all complex roots of a polynomial by Laguerre's method with deflation.
+///
+/// Coefficients start from the highest degree. Roots are found one by one starting from 0.
+/// After each root the polynomial is deflated forward when the root is smaller than the geometric mean
+/// of the remaining root magnitudes, and backward otherwise. Each root is polished against
+/// the original polynomial to remove the errors accumulated by deflations.
+///
+/// Nearly real roots are made real. Roots are ordered by the real part, then by the imaginary one.
+///
+/// # Failures
+/// ## NoConvergency
+/// A root of a deflated polynomial has not been found within the given number of iterations.
+///
+/// # Examples
+///
+/// ```
+/// use roots::find_roots_laguerre;
+/// use roots::SimpleConvergency;
+///
+/// // x^3 - 1 = 0
+/// let mut convergency = SimpleConvergency { eps: 1e-15f64, max_iter: 100 };
+/// let roots = find_roots_laguerre(&[1f64, 0f64, 0f64, -1f64], &mut convergency).unwrap();
+/// assert_eq!(roots.len(), 3);
+/// assert_eq!(roots[2].re, 1f64);
+/// assert_eq!(roots[2].im, 0f64);
+/// ```
+pub fn find_roots_laguerre<F, C>(coefficients: &[F], convergency: &mut C) -> Result<Vec<Complex<F>>, SearchError>
+where
+    F: FloatType,
+    C: Convergency<F>,
+{
+    let a = super::trim(coefficients);
+    if a.len() < 2 {
+        return Ok(Vec::new());
+    }
+
+    // Zero roots are known exactly
+    let zero_roots = a.iter().rev().take_while(|&&x| x == F::zero()).count();
+    let original: Vec<Complex<F>> = a[..a.len() - zero_roots].iter().map(|&x| Complex::from(x)).collect();
+
+    let mut roots = Vec::with_capacity(a.len() - 1);
+    let mut deflated = original.clone();
+    while deflated.len() > 1 {
+        let z = laguerre(&deflated, Complex::zero(), convergency)?;
+        let z = laguerre(&original, z, convergency).unwrap_or(z);
+
+        let m = F::from((deflated.len() - 1) as i16);
+        let mean = (deflated[deflated.len() - 1].abs() / deflated[0].abs()).powf(F::one() / m);
+        deflated = deflate(&deflated, z, z.abs() <= mean);
+        roots.push(z);
+    }
+
+    for z in roots.iter_mut() {
+        if z.im.abs() <= F::two() * F::epsilon() * z.re.abs() {
+            z.im = F::zero();
+        }
+    }
+    roots.extend((0..zero_roots).map(|_| Complex::zero()));
+    roots.sort_by(|x, y| (x.re, x.im).partial_cmp(&(y.re, y.im)).unwrap_or(Ordering::Equal));
+    Ok(roots)
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::super::*;
+    use super::*;
+
+    #[test]
+    fn test_eval_with_second_derivative() {
+        // z^3 - 2z + 1 at 1 + i
+        let coefficients = [1f64, 0f64, -2f64, 1f64].iter().map(|&a| Complex::from(a)).collect::<Vec<_>>();
+        let (p, dp, ddp) = eval_with_second_derivative(&coefficients, Complex::new(1f64, 1f64));
+        assert_eq!(p, Complex::new(-3f64, 0f64));
+        assert_eq!(dp, Complex::new(-2f64, 6f64));
+        assert_eq!(ddp, Complex::new(6f64, 6f64));
+    }
+
+    #[test]
+    fn test_deflate() {
+        // (x - 2)(x - 3) = x^2 - 5x + 6
+        let coefficients = [1f64, -5f64, 6f64].iter().map(|&a| Complex::from(a)).collect::<Vec<_>>();
+        assert_eq!(deflate(&coefficients, Complex::from(2f64), true), vec![Complex::from(1f64), Complex::from(-3f64)]);
+        assert_eq!(deflate(&coefficients, Complex::from(2f64), false), vec![Complex::from(1f64), Complex::from(-3f64)]);
+    }
+
+    #[test]
+    fn test_find_roots_laguerre() {
+        let mut convergency = SimpleConvergency { eps: 1e-15f64, max_iter: 100 };
+        assert!(find_roots_laguerre(&[0f64, 1f64], &mut convergency).unwrap().is_empty());
+
+        // (x^2 + 2x + 5) x (x - 1)
+        let roots = find_roots_laguerre(&[1f64, 1f64, 3f64, -5f64, 0f64], &mut convergency).unwrap();
+        assert_eq!(roots.len(), 4);
+        assert_float_eq!(1e-15, roots[0].re, -1f64);
+        assert_float_eq!(1e-15, roots[0].im, -2f64);
+        assert_float_eq!(1e-15, roots[1].re, -1f64);
+        assert_float_eq!(1e-15, roots[1].im, 2f64);
+        assert_eq!(roots[2], Complex::zero());
+        assert_float_eq!(1e-15, roots[3].re, 1f64);
+        assert_eq!(roots[3].im, 0f64);
+
+        // Roots of very different magnitudes need both deflations: (x - 1e-3)(x - 1)(x - 1e3)(x + 1e6)
+        let p = [1e-3f64, 1f64, 1e3f64, -1e6f64]
+            .iter()
+            .fold(Polynomial::new(vec![1f64]), |acc, &r| acc * Polynomial::new(vec![1f64, -r]));
+        let roots = find_roots_laguerre(p.coefficients(), &mut convergency).unwrap();
+        let expected = [-1e6f64, 1e-3f64, 1f64, 1e3f64];
+        for (root, &e) in roots.iter().zip(expected.iter()) {
+            assert_float_eq!(1e-15 * e.abs(), root.re, e);
+            assert_eq!(root.im, 0f64);
+        }
+    }
+
+    #[test]
+    fn test_find_roots_laguerre_wilkinson() {
+        let expected = [1f64, 2f64, 3f64, 4f64, 5f64, 6f64, 7f64, 8f64, 9f64, 10f64, 11f64, 12f64];
+        let p = expected
+            .iter()
+            .fold(Polynomial::new(vec![1f64]), |acc, &r| acc * Polynomial::new(vec![1f64, -r]));
+        let mut convergency = SimpleConvergency { eps: 1e-15f64, max_iter: 100 };
+        let roots = find_roots_laguerre(p.coefficients(), &mut convergency).unwrap();
+        assert_float_array_eq!(1e-8, roots.iter().map(|z| z.re), expected);
+        assert!(roots.iter().all(|z| z.im.abs() < 1e-8));
+    }
+
+    #[test]
+    fn test_find_roots_laguerre_f32() {
+        let mut convergency = SimpleConvergency { eps: 1e-7f32, max_iter: 100 };
+        let roots = find_roots_laguerre(&[1f32, 0f32, 1f32], &mut convergency).unwrap();
+        assert_eq!(roots.len(), 2);
+        assert_float_eq!(1e-7, roots[0].im, -1f32);
+        assert_float_eq!(1e-7, roots[1].im, 1f32);
+
+        let mut convergency = SimpleConvergency { eps: 0f32, max_iter: 1 };
+        assert_eq!(
+            find_roots_laguerre(&[1f32, -3f32, 1f32, 1f32, 1f32, 1f32], &mut convergency),
+            Err(SearchError::NoConvergency)
+        );
+    }
+}
@@ -0,0 +1,350 @@
+// Copyright (c) 2015, Mikhail Vorotilov
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without
+// modification, are permitted provided that the following conditions are met:
+//
+// * Redistributions of source code must retain the above copyright notice, this
+//   list of conditions and the following disclaimer.
+//
+// * Redistributions in binary form must reproduce the above copyright notice,
+//   this list of conditions and the following disclaimer in the documentation
+//   and/or other materials provided with the distribution.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+// AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+// IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+// FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+// DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+// CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+// OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+// OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Polynomials and the algorithms working with their coefficients.
+//!
+//! Coefficients are always given starting from the highest degree,
+//! like the arguments of find_roots_* functions: [a_n, ..., a_1, a_0]
+//! stands for a_n*x^n + ... + a_1*x + a_0.
+
+use super::Complex;
+use super::FloatType;
+use super::Roots;
+use super::SearchError;
+
+use core::cmp::Ordering;
+
+pub mod aberth;
+pub mod bernstein;
+pub mod bounds;
+pub mod chebyshev;
+pub mod dynamic;
+pub mod durand_kerner;
+pub mod eigen;
+pub mod exact;
+pub mod extrema;
+pub mod fixed;
+pub mod inequality;
+pub mod intersection;
+pub mod laguerre;
+pub mod roots_vec;
+pub mod rpoly;
+pub mod sturm;
+pub mod vca;
+
+pub use self::roots_vec::RootsVec;
+
+/// Evaluates the polynomial at x by the Horner scheme
+pub fn eval<F: FloatType>(coefficients: &[F], x: F) -> F {
+    coefficients.iter().fold(F::zero(), |acc, &a| acc * x + a)
+}
+
+/// Evaluates the polynomial and its first derivatives at x in one pass of the Horner scheme.
+///
+/// values[0] receives p(x), values[1] receives p'(x), values[2] receives p''(x) and so on,
+/// as many as the length of values.
+pub fn eval_derivatives<F: FloatType>(coefficients: &[F], x: F, values: &mut [F]) {
+    for v in values.iter_mut() {
+        *v = F::zero();
+    }
+    for &a in coefficients {
+        for k in (1..values.len()).rev() {
+            values[k] = values[k] * x + values[k - 1];
+        }
+        if let Some(v) = values.first_mut() {
+            *v = *v * x + a;
+        }
+    }
+    // The scheme gives p^(k)(x)/k!
+    let mut factorial = F::one();
+    for (k, v) in values.iter_mut().enumerate().skip(2) {
+        factorial = factorial * F::from(k as i16);
+        *v = *v * factorial;
+    }
+}
+
+/// Evaluates the polynomial and its first derivatives at a complex point, like eval_derivatives
+pub fn eval_complex_derivatives<F: FloatType>(coefficients: &[F], z: Complex<F>, values: &mut [Complex<F>]) {
+    for v in values.iter_mut() {
+        *v = Complex::zero();
+    }
+    for &a in coefficients {
+        for k in (1..values.len()).rev() {
+            values[k] = values[k] * z + values[k - 1];
+        }
+        if let Some(v) = values.first_mut() {
+            *v = *v * z + Complex::from(a);
+        }
+    }
+    let mut factorial = F::one();
+    for (k, v) in values.iter_mut().enumerate().skip(2) {
+        factorial = factorial * F::from(k as i16);
+        *v = *v * factorial;
+    }
+}
+
+/// Converts a count, like a number of coefficients, to F without wrapping around above i16::MAX.
+///
+/// Counts up to about 10^9 are exact.
+pub(super) fn from_count<F: FloatType>(n: usize) -> F {
+    let max = i16::MAX as usize;
+    F::from((n / max).min(max) as i16) * F::from(i16::MAX) + F::from((n % max) as i16)
+}
+
+/// Bound on the rounding error of the Horner scheme at a point of the modulus r.
+///
+/// Moduli of coefficients start from the highest degree. Iterative solvers stop refining
+/// an approximation once the value of the polynomial is below this bound.
+pub(super) fn rounding_error<F, I>(abs_coefficients: I, r: F) -> F
+where
+    F: FloatType,
+    I: Iterator<Item = F>,
+{
+    let (n, abs_p) = abs_coefficients.fold((0, F::zero()), |(n, acc), a| (n + 1, acc * r + a));
+    F::four() * from_count::<F>(n) * F::epsilon() * abs_p
+}
+
+/// Skips leading zero coefficients
+pub fn trim<F: FloatType>(coefficients: &[F]) -> &[F] {
+    let first = coefficients.iter().position(|&a| a != F::zero()).unwrap_or(coefficients.len());
+    &coefficients[first..]
+}
+
+/// Pairs of coefficients of two polynomials aligned by the constant terms,
+/// with zeros in place of the missing highest degree coefficients of the shorter one
+pub(super) fn zip_aligned<'a, T: Copy>(a: &'a [T], b: &'a [T], zero: T) -> impl Iterator<Item = (T, T)> + 'a {
+    let n = a.len().max(b.len());
+    let at = move |c: &[T], i: usize| if i + c.len() >= n { c[i + c.len() - n] } else { zero };
+    (0..n).map(move |i| (at(a, i), at(b, i)))
+}
+
+/// Coefficients of p - q; lengths of p and q may differ
+pub(super) fn subtract<F: FloatType>(p: &[F], q: &[F]) -> Vec<F> {
+    zip_aligned(p, q, F::zero()).map(|(a, b)| a - b).collect()
+}
+
+/// Sign of the polynomial at x, or Equal if |p(x)| does not exceed the rounding errors
+pub(super) fn sign<F: FloatType>(coefficients: &[F], x: F) -> Ordering {
+    let y = eval(coefficients, x);
+    let scale = coefficients.iter().fold(F::zero(), |acc, &a| acc * x.abs() + a.abs());
+    let tolerance = F::four() * from_count::<F>(coefficients.len()) * F::epsilon() * scale;
+    if y > tolerance {
+        Ordering::Greater
+    } else if y < -tolerance {
+        Ordering::Less
+    } else {
+        Ordering::Equal
+    }
+}
+
+/// Real roots as eigenvalues of the companion matrix, including nearly real ones
+/// where the polynomial is negligible, since the QR algorithm may split a multiple root into a complex pair
+pub(super) fn find_nearly_real_roots_eigen<F: FloatType>(coefficients: &[F]) -> Result<Vec<F>, SearchError> {
+    Ok(eigen::find_roots_eigen_complex(coefficients)?
+        .into_iter()
+        .filter(|root| root.im == F::zero() || sign(coefficients, root.re) == Ordering::Equal)
+        .map(|root| root.re)
+        .collect())
+}
+
+/// Sorts approximate real roots and merges the ones with no sign change of the polynomial
+/// between them, like approximations of a multiple root, into their mean.
+///
+/// Returns separate roots in ascending order with the numbers of approximations merged into them.
+pub(super) fn separate_roots<F: FloatType>(coefficients: &[F], mut roots: Vec<F>) -> Vec<(F, usize)> {
+    roots.sort_by(|x, y| x.partial_cmp(y).unwrap_or(Ordering::Equal));
+    let mut separate: Vec<(F, usize)> = Vec::with_capacity(roots.len());
+    for root in roots {
+        let merged = |last: F| last == root || sign(coefficients, (last + root) / F::two()) == Ordering::Equal;
+        match separate.last_mut() {
+            Some((last, count)) if merged(*last) => {
+                *last = (*last * from_count(*count) + root) / from_count(*count + 1);
+                *count += 1;
+            }
+            _ => separate.push((root, 1)),
+        }
+    }
+    separate
+}
+
+/// Monic coefficients, starting from the highest degree, of the polynomial with given roots and multiplicities.
+///
+/// Coefficients are multiplied out from the factors, following Vieta's formulas. A root with a non-zero
+/// imaginary part stands for a pair of complex conjugate roots, so coefficients are always real.
+///
+/// # Examples
+///
+/// ```
+/// use roots::coefficients_from_roots;
+/// use roots::Complex;
+///
+/// // (x - 2)^2 (x^2 + 1) = x^4 - 4x^3 + 5x^2 - 4x + 4
+/// let coefficients = coefficients_from_roots(&[(Complex::from(2f64), 2), (Complex::new(0f64, 1f64), 1)]);
+/// assert_eq!(coefficients, vec![1f64, -4f64, 5f64, -4f64, 4f64]);
+/// ```
+pub fn coefficients_from_roots<F: FloatType>(roots: &[(Complex<F>, usize)]) -> Vec<F> {
+    let mut coefficients = vec![F::one()];
+    for &(root, multiplicity) in roots {
+        let factor = if root.im == F::zero() {
+            vec![F::one(), -root.re]
+        } else {
+            vec![F::one(), -F::two() * root.re, root.norm_sqr()]
+        };
+        for _ in 0..multiplicity {
+            let mut product = vec![F::zero(); coefficients.len() + factor.len() - 1];
+            for (i, &a) in coefficients.iter().enumerate() {
+                for (j, &b) in factor.iter().enumerate() {
+                    product[i + j] = product[i + j] + a * b;
+                }
+            }
+            coefficients = product;
+        }
+    }
+    coefficients
+}
+
+/// Accuracy of approximate roots of a polynomial
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RootsCheck<F: FloatType> {
+    /// Number of the roots checked
+    pub count: usize,
+    /// The largest |p(x)| over the roots
+    pub max_residual: F,
+    /// The largest relative backward error |p(x)| / (|a_n|*|x|^n + ... + |a_0|) over the roots:
+    /// the smallest relative change of coefficients making x an exact root
+    pub max_backward_error: F,
+}
+
+/// Compares roots, such as the result of a find_roots_* function, with the polynomial
+/// given by coefficients starting from the highest degree.
+///
+/// # Examples
+///
+/// ```
+/// use roots::check_roots;
+/// use roots::find_roots_quadratic;
+///
+/// let check = check_roots(&[1f64, 0f64, -2f64], find_roots_quadratic(1f64, 0f64, -2f64));
+/// assert_eq!(check.count, 2);
+/// assert!(check.max_backward_error < 1e-15f64);
+/// ```
+pub fn check_roots<F, I>(coefficients: &[F], roots: I) -> RootsCheck<F>
+where
+    F: FloatType,
+    I: IntoIterator<Item = F>,
+{
+    let mut check = RootsCheck {
+        count: 0,
+        max_residual: F::zero(),
+        max_backward_error: F::zero(),
+    };
+    for x in roots {
+        let residual = eval(coefficients, x).abs();
+        let scale = coefficients.iter().fold(F::zero(), |acc, &a| acc * x.abs() + a.abs());
+        let backward_error = if scale > F::zero() { residual / scale } else { residual };
+        check.count += 1;
+        if residual > check.max_residual {
+            check.max_residual = residual;
+        }
+        if backward_error > check.max_backward_error {
+            check.max_backward_error = backward_error;
+        }
+    }
+    check
+}
+
+/// Finds real roots of a polynomial of degree up to 4 by the analytical solvers.
+///
+/// Returns None if the degree is higher than 4.
+pub fn find_roots_analytical<F: FloatType>(coefficients: &[F]) -> Option<Roots<F>> {
+    let a = trim(coefficients);
+    let z = F::zero();
+    match a.len() {
+        0 => Some(super::find_roots_linear(z, z)),
+        1 => Some(super::find_roots_linear(z, a[0])),
+        2 => Some(super::find_roots_linear(a[0], a[1])),
+        3 => Some(super::find_roots_quadratic(a[0], a[1], a[2])),
+        4 => Some(super::find_roots_cubic(a[0], a[1], a[2], a[3])),
+        5 => Some(super::find_roots_quartic(a[0], a[1], a[2], a[3], a[4])),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_from_count() {
+        assert_eq!(from_count::<f64>(0), 0f64);
+        assert_eq!(from_count::<f64>(32767), 32767f64);
+        assert_eq!(from_count::<f64>(40000), 40000f64);
+        assert_eq!(from_count::<f32>(1000000), 1000000f32);
+    }
+
+    #[test]
+    fn test_eval_derivatives() {
+        // x^3 - 2x^2 + 3x - 4 at x = 2: p = 2, p' = 7, p'' = 8, p''' = 6, p'''' = 0
+        let mut values = [0f64; 5];
+        eval_derivatives(&[1f64, -2f64, 3f64, -4f64], 2f64, &mut values);
+        assert_eq!(values, [2f64, 7f64, 8f64, 6f64, 0f64]);
+        assert_eq!(eval(&[1f64, -2f64, 3f64, -4f64], 2f64), 2f64);
+        assert_eq!(eval::<f32>(&[], 2f32), 0f32);
+
+        // The same polynomial at x = i: p = -2 + 2i, p' = -4i, p'' = -4 + 6i
+        let mut values = [Complex::zero(); 3];
+        eval_complex_derivatives(&[1f64, -2f64, 3f64, -4f64], Complex::new(0f64, 1f64), &mut values);
+        assert_eq!(values, [Complex::new(-2f64, 2f64), Complex::new(0f64, -4f64), Complex::new(-4f64, 6f64)]);
+    }
+
+    #[test]
+    fn test_coefficients_from_roots() {
+        assert_eq!(coefficients_from_roots::<f64>(&[]), vec![1f64]);
+        assert_eq!(coefficients_from_roots(&[(Complex::from(0f32), 3)]), vec![1f32, 0f32, 0f32, 0f32]);
+        // (x + 1)(x - 3)(x^2 - 2x + 5)
+        assert_eq!(
+            coefficients_from_roots(&[(Complex::from(-1f64), 1), (Complex::from(3f64), 1), (Complex::new(1f64, -2f64), 1)]),
+            vec![1f64, -4f64, 6f64, -4f64, -15f64]
+        );
+    }
+
+    #[test]
+    fn test_check_roots() {
+        let check = check_roots(&[1f64, -3f64, 2f64], vec![1f64, 2.5f64]);
+        assert_eq!(check.count, 2);
+        assert_eq!(check.max_residual, 0.75f64);
+        assert_eq!(check.max_backward_error, 0.75f64 / 15.75f64);
+
+        let check = check_roots(&[0f64], RootsVec::new());
+        assert_eq!(check.count, 0);
+        assert_eq!(check.max_residual, 0f64);
+    }
+
+    #[test]
+    fn test_find_roots_analytical() {
+        assert_float_array_eq!(1e-15, find_roots_analytical(&[0f64, 0f64, 1f64, 0f64, -1f64]).unwrap(), [-1f64, 1f64]);
+        assert_eq!(find_roots_analytical(&[0f32, 1f32]).unwrap().next(), None);
+        assert!(find_roots_analytical(&[1f64, 0f64, 0f64, 0f64, 0f64, -1f64]).is_none());
+    }
+}
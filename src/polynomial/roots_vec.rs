@@ -0,0 +1,89 @@
+// Copyright (c) 2015, Mikhail Vorotilov
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without
+// modification, are permitted provided that the following conditions are met:
+//
+// * Redistributions of source code must retain the above copyright notice, this
+//   list of conditions and the following disclaimer.
+//
+// * Redistributions in binary form must reproduce the above copyright notice,
+//   this list of conditions and the following disclaimer in the documentation
+//   and/or other materials provided with the distribution.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+// AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+// IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+// FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+// DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+// CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+// OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+// OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use super::super::FloatType;
+use super::super::Roots;
+use core::iter::FusedIterator;
+
+/// Growable list of real roots, returned by the solvers of polynomials of any degree.
+///
+/// Like Roots, it is an iterator over the found roots.
+/// Roots are kept in the increasing order.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct RootsVec<F>
+where
+    F: FloatType,
+{
+    roots: Vec<F>,
+    cursor: usize,
+}
+
+impl<F> RootsVec<F>
+where
+    F: FloatType,
+{
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add_new_root(&mut self, root: F) {
+        let i = self.roots.iter().position(|&r| root < r).unwrap_or(self.roots.len());
+        self.roots.insert(i, root);
+    }
+
+    /// All roots, including ones already returned by the iterator
+    pub fn as_slice(&self) -> &[F] {
+        &self.roots
+    }
+}
+
+impl<F> From<Roots<F>> for RootsVec<F>
+where
+    F: FloatType,
+{
+    fn from(roots: Roots<F>) -> Self {
+        let mut result = Self::new();
+        for root in roots {
+            result.add_new_root(root);
+        }
+        result
+    }
+}
+
+impl<F> Iterator for RootsVec<F>
+where
+    F: FloatType,
+{
+    type Item = F;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let root = self.roots.get(self.cursor).cloned();
+        if root.is_some() {
+            self.cursor += 1;
+        }
+        root
+    }
+}
+
+impl<F> FusedIterator for RootsVec<F> where F: FloatType {}
@@ -0,0 +1,707 @@
+// Copyright (c) 2015, Mikhail Vorotilov
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without
+// modification, are permitted provided that the following conditions are met:
+//
+// * Redistributions of source code must retain the above copyright notice, this
+//   list of conditions and the following disclaimer.
+//
+// * Redistributions in binary form must reproduce the above copyright notice,
+//   this list of conditions and the following disclaimer in the documentation
+//   and/or other materials provided with the distribution.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+// AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+// IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+// FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+// DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+// CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+// OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+// OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use super::super::Complex;
+use super::super::FloatType;
+use super::super::SearchError;
+use super::RootsVec;
+
+use core::cmp::Ordering;
+
+/// How the next K polynomial is computed from the remainders
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Kind {
+    /// The remainder of K is divided by c
+    ByC,
+    /// The remainder of K is divided by d
+    ByD,
+    /// K is almost divisible by the quadratic factor
+    Divisible,
+}
+
+/// State of the Jenkins-Traub iteration, a port of RPOLY (ACM TOMS algorithm 493).
+/// All polynomials start from the highest degree; p has the current degree n.
+struct Rpoly<F: FloatType> {
+    p: Vec<F>,
+    qp: Vec<F>,
+    k: Vec<F>,
+    qk: Vec<F>,
+    n: usize,
+    // The quadratic factor x^2 + u*x + v and the real shift
+    u: F,
+    v: F,
+    // Remainders of p and k divided by the quadratic factor and scalars derived from them
+    a: F,
+    b: F,
+    c: F,
+    d: F,
+    a1: F,
+    a3: F,
+    a7: F,
+    e: F,
+    f: F,
+    g: F,
+    h: F,
+    // Zeros found by the last iteration, smaller and larger
+    szr: F,
+    szi: F,
+    lzr: F,
+    // Precision and the error bounds of additions and multiplications
+    eta: F,
+    are: F,
+    mre: F,
+}
+
+/// Divides p by x^2 + u*x + v into the quotient q; the remainder is b*(x + u) + a
+fn quadratic_division<F: FloatType>(p: &[F], u: F, v: F, q: &mut [F]) -> (F, F) {
+    let mut b = p[0];
+    q[0] = b;
+    let mut a = p[1] - u * b;
+    q[1] = a;
+    for i in 2..p.len() {
+        let c = p[i] - u * a - v * b;
+        q[i] = c;
+        b = a;
+        a = c;
+    }
+    (a, b)
+}
+
+/// Roots of a*x^2 + b1*x + c: (smaller real, smaller imaginary, larger real, larger imaginary)
+fn quadratic_roots<F: FloatType>(a: F, b1: F, c: F) -> (F, F, F, F) {
+    if a == F::zero() {
+        let sr = if b1 != F::zero() { -c / b1 } else { F::zero() };
+        return (sr, F::zero(), F::zero(), F::zero());
+    }
+    if c == F::zero() {
+        return (F::zero(), F::zero(), -b1 / a, F::zero());
+    }
+
+    // Discriminant computed avoiding overflows
+    let b = b1 / F::two();
+    let (e, d) = if b.abs() < c.abs() {
+        let e = b * (b / c.abs()) - if c < F::zero() { -a } else { a };
+        (e, e.abs().sqrt() * c.abs().sqrt())
+    } else {
+        let e = F::one() - (a / b) * (c / b);
+        (e, e.abs().sqrt() * b.abs())
+    };
+    if e < F::zero() {
+        let re = -b / a;
+        let im = (d / a).abs();
+        (re, im, re, -im)
+    } else {
+        let d = if b >= F::zero() { -d } else { d };
+        let lr = (-b + d) / a;
+        let sr = if lr != F::zero() { (c / lr) / a } else { F::zero() };
+        (sr, F::zero(), lr, F::zero())
+    }
+}
+
+impl<F: FloatType> Rpoly<F> {
+    /// Computes the remainder of K and the scalars for the next K polynomial
+    fn calculate_scalars(&mut self) -> Kind {
+        let n = self.n;
+        let (c, d) = quadratic_division(&self.k[..n], self.u, self.v, &mut self.qk);
+        self.c = c;
+        self.d = d;
+        let hundred_eta = F::from(100i16) * self.eta;
+        if c.abs() <= self.k[n - 1].abs() * hundred_eta && d.abs() <= self.k[n - 2].abs() * hundred_eta {
+            return Kind::Divisible;
+        }
+
+        let (a, b, u, v) = (self.a, self.b, self.u, self.v);
+        if d.abs() >= c.abs() {
+            self.e = a / d;
+            self.f = c / d;
+            self.g = u * b;
+            self.h = v * b;
+            self.a3 = (a + self.g) * self.e + self.h * (b / d);
+            self.a1 = b * self.f - a;
+            self.a7 = (self.f + u) * a + self.h;
+            Kind::ByD
+        } else {
+            self.e = a / c;
+            self.f = d / c;
+            self.g = u * self.e;
+            self.h = v * b;
+            self.a3 = a * self.e + (self.h / c + self.g) * b;
+            self.a1 = b - a * (d / c);
+            self.a7 = a + self.g * d + self.h * self.f;
+            Kind::ByC
+        }
+    }
+
+    /// Computes the next K polynomial
+    fn next_k(&mut self, kind: Kind) {
+        let n = self.n;
+        if kind == Kind::Divisible {
+            self.k[0] = F::zero();
+            self.k[1] = F::zero();
+            for i in 2..n {
+                self.k[i] = self.qk[i - 2];
+            }
+            return;
+        }
+
+        let temp = if kind == Kind::ByC { self.b } else { self.a };
+        if self.a1.abs() <= temp.abs() * self.eta * F::from(10i16) {
+            // The leading coefficient vanishes, so K is not scaled
+            self.k[0] = F::zero();
+            self.k[1] = -self.a7 * self.qp[0];
+            for i in 2..n {
+                self.k[i] = self.a3 * self.qk[i - 2] - self.a7 * self.qp[i - 1];
+            }
+        } else {
+            self.a7 = self.a7 / self.a1;
+            self.a3 = self.a3 / self.a1;
+            self.k[0] = self.qp[0];
+            self.k[1] = self.qp[1] - self.a7 * self.qp[0];
+            for i in 2..n {
+                self.k[i] = self.a3 * self.qk[i - 2] - self.a7 * self.qp[i - 1] + self.qp[i];
+            }
+        }
+    }
+
+    /// Estimates of the new quadratic factor
+    fn new_estimate(&self, kind: Kind) -> (F, F) {
+        if kind == Kind::Divisible {
+            return (F::zero(), F::zero());
+        }
+        let (n, u, v) = (self.n, self.u, self.v);
+        let (a4, a5) = if kind == Kind::ByD {
+            ((self.a + self.g) * self.f + self.h, (self.f + u) * self.c + v * self.d)
+        } else {
+            (self.a + u * self.b + self.h * self.f, self.c + (u + v * self.f) * self.d)
+        };
+
+        let b1 = -self.k[n - 1] / self.p[n];
+        let b2 = -(self.k[n - 2] + b1 * self.p[n - 1]) / self.p[n];
+        let c1 = v * b2 * self.a1;
+        let c2 = b1 * self.a7;
+        let c3 = b1 * b1 * self.a3;
+        let c4 = c1 - c2 - c3;
+        let temp = a5 + b1 * a4 - c4;
+        if temp == F::zero() {
+            (F::zero(), F::zero())
+        } else {
+            (u - (u * (c3 + c2) + v * (b1 * self.a1 + b2 * self.a7)) / temp, v * (F::one() + c4 / temp))
+        }
+    }
+
+    /// Divides p by the current quadratic factor
+    fn divide_p(&mut self) {
+        let (a, b) = quadratic_division(&self.p[..=self.n], self.u, self.v, &mut self.qp);
+        self.a = a;
+        self.b = b;
+    }
+
+    /// Stage 3: variable-shift iteration for a quadratic factor starting from x^2 + uu*x + vv.
+    /// Returns true if two zeros are found.
+    fn quadratic_iteration(&mut self, uu: F, vv: F) -> bool {
+        let n = self.n;
+        self.u = uu;
+        self.v = vv;
+        let mut tried = false;
+        let mut j = 0;
+        let mut omp = F::zero();
+        let mut relstp = F::zero();
+        loop {
+            let (szr, szi, lzr, _) = quadratic_roots(F::one(), self.u, self.v);
+            self.szr = szr;
+            self.szi = szi;
+            self.lzr = lzr;
+            // Zeros of the quadratic must have about the same magnitude
+            if (szr.abs() - lzr.abs()).abs() > lzr.abs() / F::from(100i16) {
+                return false;
+            }
+
+            // Evaluates p at a zero of the quadratic and bounds the rounding error
+            self.divide_p();
+            let mp = (self.a - szr * self.b).abs() + (szi * self.b).abs();
+            let zm = self.v.abs().sqrt();
+            let t = -szr * self.b;
+            let mut ee = self.qp[1..n].iter().fold(F::two() * self.qp[0].abs(), |acc, &q| acc * zm + q.abs());
+            ee = ee * zm + (self.a + t).abs();
+            let (are, mre) = (self.are, self.mre);
+            ee = ee * (F::five() * mre + F::four() * are) - (F::five() * mre + F::two() * are) * ((self.a + t).abs() + self.b.abs() * zm)
+                + F::two() * are * t.abs();
+            if mp <= F::from(20i16) * ee {
+                return true;
+            }
+
+            j += 1;
+            if j > 20 {
+                return false;
+            }
+            if j >= 2 && relstp <= F::one() / F::from(100i16) && mp >= omp && !tried {
+                // A cluster of zeros stalls the convergence: a few fixed-shift steps close to it
+                relstp = if relstp < self.eta { self.eta } else { relstp }.sqrt();
+                self.u = self.u - self.u * relstp;
+                self.v = self.v + self.v * relstp;
+                self.divide_p();
+                for _ in 0..5 {
+                    let kind = self.calculate_scalars();
+                    self.next_k(kind);
+                }
+                tried = true;
+                j = 0;
+            }
+            omp = mp;
+
+            let kind = self.calculate_scalars();
+            self.next_k(kind);
+            let kind = self.calculate_scalars();
+            let (ui, vi) = self.new_estimate(kind);
+            if vi == F::zero() {
+                return false;
+            }
+            relstp = ((vi - self.v) / vi).abs();
+            self.u = ui;
+            self.v = vi;
+        }
+    }
+
+    /// Stage 3: variable-shift iteration for a real zero starting from s.
+    /// Returns Ok if a zero is found, and Err with the last shift if a cluster of zeros
+    /// near the real axis is suspected, or None if the iteration has failed.
+    fn real_iteration(&mut self, mut s: F) -> Option<Result<(), F>> {
+        let n = self.n;
+        let mut j = 0;
+        let mut omp = F::zero();
+        let mut t = F::zero();
+        loop {
+            // Evaluates p at s and bounds the rounding error
+            let mut pv = self.p[0];
+            self.qp[0] = pv;
+            for i in 1..=n {
+                pv = pv * s + self.p[i];
+                self.qp[i] = pv;
+            }
+            let mp = pv.abs();
+            let ms = s.abs();
+            let ee = self.qp[1..=n]
+                .iter()
+                .fold((self.mre / (self.are + self.mre)) * self.qp[0].abs(), |acc, &q| acc * ms + q.abs());
+            if mp <= F::from(20i16) * ((self.are + self.mre) * ee - self.mre * mp) {
+                self.szr = s;
+                self.szi = F::zero();
+                return Some(Ok(()));
+            }
+
+            j += 1;
+            if j > 10 {
+                return None;
+            }
+            if j >= 2 && t.abs() <= (s - t).abs() / F::from(1000i16) && mp >= omp {
+                return Some(Err(s));
+            }
+            omp = mp;
+
+            // The next K polynomial and the next shift
+            let mut kv = self.k[0];
+            self.qk[0] = kv;
+            for i in 1..n {
+                kv = kv * s + self.k[i];
+                self.qk[i] = kv;
+            }
+            let ten_eta = F::from(10i16) * self.eta;
+            if kv.abs() <= self.k[n - 1].abs() * ten_eta {
+                self.k[0] = F::zero();
+                for i in 1..n {
+                    self.k[i] = self.qk[i - 1];
+                }
+            } else {
+                let t = -pv / kv;
+                self.k[0] = self.qp[0];
+                for i in 1..n {
+                    self.k[i] = t * self.qk[i - 1] + self.qp[i];
+                }
+            }
+            let kv = self.k[1..n].iter().fold(self.k[0], |acc, &k| acc * s + k);
+            t = if kv.abs() > self.k[n - 1].abs() * ten_eta { -pv / kv } else { F::zero() };
+            s = s + t;
+        }
+    }
+
+    /// Stage 2: fixed-shift iteration with the current quadratic factor and the real shift sr,
+    /// followed by stage 3 when the estimates settle. Returns the number of zeros found.
+    fn fixed_shift(&mut self, l2: usize, sr: F) -> usize {
+        let n = self.n;
+        let mut betav = F::one() / F::four();
+        let mut betas = F::one() / F::four();
+        let mut oss = sr;
+        let mut ovv = self.v;
+        let mut otv = F::zero();
+        let mut ots = F::zero();
+
+        self.divide_p();
+        let mut kind = self.calculate_scalars();
+        for j in 0..l2 {
+            self.next_k(kind);
+            kind = self.calculate_scalars();
+            let (ui, vi) = self.new_estimate(kind);
+            let vv = vi;
+            let ss = if self.k[n - 1] != F::zero() { -self.p[n] / self.k[n - 1] } else { F::zero() };
+            let mut tv = F::one();
+            let mut ts = F::one();
+            if j != 0 && kind != Kind::Divisible {
+                // Convergence is tested by the ratios of the successive estimates
+                if vv != F::zero() {
+                    tv = ((vv - ovv) / vv).abs();
+                }
+                if ss != F::zero() {
+                    ts = ((ss - oss) / ss).abs();
+                }
+                let tvv = if tv < otv { tv * otv } else { F::one() };
+                let tss = if ts < ots { ts * ots } else { F::one() };
+                let vpass = tvv < betav;
+                let spass = tss < betas;
+                if spass || vpass {
+                    let (svu, svv) = (self.u, self.v);
+                    let svk = self.k.clone();
+                    let mut s = ss;
+                    let mut vtry = false;
+                    let mut stry = false;
+                    let mut try_quadratic = !((spass && !vpass) || tss < tvv);
+                    let (mut ui, mut vi) = (ui, vi);
+                    loop {
+                        if try_quadratic {
+                            if self.quadratic_iteration(ui, vi) {
+                                return 2;
+                            }
+                            vtry = true;
+                            betav = betav / F::four();
+                        }
+                        if !try_quadratic || (!stry && spass) {
+                            if try_quadratic {
+                                self.k.copy_from_slice(&svk);
+                            }
+                            match self.real_iteration(s) {
+                                Some(Ok(())) => return 1,
+                                Some(Err(cluster)) => {
+                                    // Zeros near the real axis: a quadratic factor around the cluster
+                                    stry = true;
+                                    betas = betas / F::four();
+                                    s = cluster;
+                                    ui = -(s + s);
+                                    vi = s * s;
+                                    try_quadratic = true;
+                                    continue;
+                                }
+                                None => {
+                                    stry = true;
+                                    betas = betas / F::four();
+                                }
+                            }
+                        }
+
+                        // Restores the state before the variable-shift iterations
+                        self.u = svu;
+                        self.v = svv;
+                        self.k.copy_from_slice(&svk);
+                        if vpass && !vtry {
+                            try_quadratic = true;
+                            continue;
+                        }
+                        break;
+                    }
+                    self.divide_p();
+                    kind = self.calculate_scalars();
+                }
+            }
+            ovv = vv;
+            oss = ss;
+            otv = tv;
+            ots = ts;
+        }
+        0
+    }
+}
+
+/// Finds all roots of a polynomial with real coefficients by the Jenkins-Traub three-stage algorithm (RPOLY).
+///
+/// Coefficients start from the highest degree. Zeros are found one real zero or one quadratic factor
+/// at a time, and the polynomial is deflated after each of them: stage 1 accentuates small zeros
+/// with no shift, stage 2 uses a fixed shift on a circle below the smallest zero magnitude,
+/// and stage 3 refines a real zero or a quadratic factor with variable shifts.
+///
+/// Real roots are returned in ascending order, multiple roots repeated, and each complex conjugate pair
+/// is returned once, as the root with the positive imaginary part.
+///
+/// # Failures
+/// ## NoConvergency
+/// No zero has been found after 20 shifts.
+///
+/// # Examples
+///
+/// ```
+/// use roots::find_roots_rpoly;
+///
+/// // (x - 1)(x - 2)(x^2 + 1) = 0
+/// let (real, complex) = find_roots_rpoly(&[1f64, -3f64, 3f64, -3f64, 2f64]).unwrap();
+/// assert_eq!(real.as_slice().len(), 2);
+/// assert_eq!(complex.len(), 1);
+/// assert!((complex[0].im - 1f64).abs() < 1e-15f64);
+/// ```
+pub fn find_roots_rpoly<F: FloatType>(coefficients: &[F]) -> Result<(RootsVec<F>, Vec<Complex<F>>), SearchError> {
+    let mut real = RootsVec::new();
+    let mut complex = Vec::new();
+    let a = super::trim(coefficients);
+    if a.len() < 2 {
+        return Ok((real, complex));
+    }
+
+    // Zero roots are known exactly
+    let zero_roots = a.iter().rev().take_while(|&&x| x == F::zero()).count();
+    for _ in 0..zero_roots {
+        real.add_new_root(F::zero());
+    }
+    let mut p = a[..a.len() - zero_roots].to_vec();
+    super::vca::normalize(&mut p);
+
+    let n = p.len() - 1;
+    let eta = F::epsilon();
+    let mut state = Rpoly {
+        p,
+        qp: Vec::new(),
+        k: Vec::new(),
+        qk: Vec::new(),
+        n,
+        u: F::zero(),
+        v: F::zero(),
+        a: F::zero(),
+        b: F::zero(),
+        c: F::zero(),
+        d: F::zero(),
+        a1: F::zero(),
+        a3: F::zero(),
+        a7: F::zero(),
+        e: F::zero(),
+        f: F::zero(),
+        g: F::zero(),
+        h: F::zero(),
+        szr: F::zero(),
+        szi: F::zero(),
+        lzr: F::zero(),
+        eta,
+        are: eta,
+        mre: eta,
+    };
+
+    // Shifts start at 45 degrees and rotate by 94 degrees
+    let mut xx = F::one() / F::two().sqrt();
+    let mut yy = -xx;
+    let rotation = F::pi() * F::from(94i16) / F::from(180i16);
+    let (cosr, sinr) = (rotation.cos(), rotation.sin());
+
+    loop {
+        let n = state.n;
+        let p = &state.p;
+        if n == 1 {
+            real.add_new_root(-p[1] / p[0]);
+            break;
+        }
+        if n == 2 {
+            let (sr, si, lr, _) = quadratic_roots(p[0], p[1], p[2]);
+            if si == F::zero() {
+                real.add_new_root(sr);
+                real.add_new_root(lr);
+            } else {
+                complex.push(Complex::new(sr, si.abs()));
+            }
+            break;
+        }
+
+        // Stage 1: the derivative scaled to the leading coefficient of p, with no shifts
+        let n_f = F::from(n as i16);
+        let mut k: Vec<F> = (0..n).map(|i| F::from((n - i) as i16) * p[i] / n_f).collect();
+        let (aa, bb) = (p[n], p[n - 1]);
+        let mut zerok = k[n - 1] == F::zero();
+        for _ in 0..5 {
+            let cc = k[n - 1];
+            if zerok {
+                // The constant term of K vanishes: divide by x
+                for j in (1..n).rev() {
+                    k[j] = k[j - 1];
+                }
+                k[0] = F::zero();
+                zerok = k[n - 1] == F::zero();
+            } else {
+                let t = -aa / cc;
+                for j in (1..n).rev() {
+                    k[j] = t * k[j - 1] + p[j];
+                }
+                k[0] = p[0];
+                zerok = k[n - 1].abs() <= bb.abs() * eta * F::from(10i16);
+            }
+        }
+
+        // Lower bound on magnitudes of zeros: the positive root of |a_n| x^n + ... + |a_1| x - |a_0|
+        let mut pt: Vec<F> = p.iter().map(|x| x.abs()).collect();
+        pt[n] = -pt[n];
+        let mut x = (-pt[n] / pt[0]).powf(F::one() / n_f);
+        if pt[n - 1] != F::zero() {
+            let xm = -pt[n] / pt[n - 1];
+            if xm < x {
+                x = xm;
+            }
+        }
+        loop {
+            let xm = x / F::from(10i16);
+            if super::eval(&pt, xm) <= F::zero() {
+                break;
+            }
+            x = xm;
+        }
+        let mut dx = x;
+        while (dx / x).abs() > F::from(5i16) / F::from(1000i16) {
+            let mut ff = pt[0];
+            let mut df = ff;
+            for &c in pt[1..n].iter() {
+                ff = ff * x + c;
+                df = df * x + ff;
+            }
+            ff = ff * x + pt[n];
+            dx = ff / df;
+            x = x - dx;
+        }
+        let bound = x;
+
+        state.qp = vec![F::zero(); n + 1];
+        state.qk = vec![F::zero(); n];
+        state.k = k.clone();
+        let mut found = 0;
+        for cnt in 1..=20 {
+            let xxx = cosr * xx - sinr * yy;
+            yy = sinr * xx + cosr * yy;
+            xx = xxx;
+            let sr = bound * xx;
+            state.u = -F::two() * sr;
+            state.v = bound * bound;
+            found = state.fixed_shift(20 * cnt, sr);
+            if found > 0 {
+                break;
+            }
+            state.k.copy_from_slice(&k);
+        }
+
+        match found {
+            0 => return Err(SearchError::NoConvergency),
+            1 => real.add_new_root(state.szr),
+            _ => {
+                if state.szi == F::zero() {
+                    real.add_new_root(state.szr);
+                    real.add_new_root(state.lzr);
+                } else {
+                    complex.push(Complex::new(state.szr, state.szi.abs()));
+                }
+            }
+        }
+
+        // Deflation by the found zeros
+        state.n -= found;
+        state.p = state.qp[..=state.n].to_vec();
+    }
+
+    complex.sort_by(|x, y| (x.re, x.im).partial_cmp(&(y.re, y.im)).unwrap_or(Ordering::Equal));
+    Ok((real, complex))
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::super::*;
+
+    /// Coefficients of the monic polynomial with the given real roots
+    fn from_roots(roots: &[f64]) -> Vec<f64> {
+        roots
+            .iter()
+            .fold(Polynomial::new(vec![1f64]), |acc, &r| acc * Polynomial::new(vec![1f64, -r]))
+            .coefficients()
+            .to_vec()
+    }
+
+    #[test]
+    fn test_find_roots_rpoly() {
+        let (real, complex) = find_roots_rpoly(&[0f64, 3f64]).unwrap();
+        assert!(real.as_slice().is_empty() && complex.is_empty());
+
+        let (real, complex) = find_roots_rpoly(&[2f64, -4f64]).unwrap();
+        assert_float_array_eq!(1e-15, real, [2f64]);
+        assert!(complex.is_empty());
+
+        // (x^2 + 2x + 5) x^2 (x - 3)
+        let (real, complex) = find_roots_rpoly(&[1f64, -1f64, -1f64, -15f64, 0f64, 0f64]).unwrap();
+        assert_float_array_eq!(1e-14, real, [0f64, 0f64, 3f64]);
+        assert_eq!(complex.len(), 1);
+        assert_float_eq!(1e-14, complex[0].re, -1f64);
+        assert_float_eq!(1e-14, complex[0].im, 2f64);
+    }
+
+    #[test]
+    fn test_find_roots_rpoly_classic() {
+        // Wilkinson's polynomial of degree 10
+        let expected = [1f64, 2f64, 3f64, 4f64, 5f64, 6f64, 7f64, 8f64, 9f64, 10f64];
+        let (real, complex) = find_roots_rpoly(&from_roots(&expected)).unwrap();
+        assert_float_array_eq!(1e-9, real, expected);
+        assert!(complex.is_empty());
+
+        // Zeros of very different magnitudes
+        let expected = [1e-5f64, 1e-4f64, 1e-3f64, 1e-2f64, 1e-1f64];
+        let (real, _) = find_roots_rpoly(&from_roots(&expected)).unwrap();
+        for (&x, &e) in real.as_slice().iter().zip(expected.iter()) {
+            assert_float_eq!(1e-12 * e, x, e);
+        }
+
+        // A triple zero near a cluster of simple ones
+        let expected = [0.1f64, 0.1f64, 0.1f64, 0.5f64, 0.6f64, 0.7f64];
+        let (real, complex) = find_roots_rpoly(&from_roots(&expected)).unwrap();
+        assert_eq!(real.as_slice().len() + 2 * complex.len(), 6);
+        for root in real.as_slice().iter().cloned().map(Complex::from).chain(complex.iter().cloned()) {
+            assert!(expected.iter().any(|&e| (root - Complex::from(e)).abs() < 1e-4f64));
+        }
+
+        // Zeros on the unit circle: x^20 - 1
+        let mut coefficients = [0f64; 21];
+        coefficients[0] = 1f64;
+        coefficients[20] = -1f64;
+        let (real, complex) = find_roots_rpoly(&coefficients).unwrap();
+        assert_float_array_eq!(1e-14, real, [-1f64, 1f64]);
+        assert_eq!(complex.len(), 9);
+        for root in complex {
+            assert_float_eq!(1e-14, root.abs(), 1f64);
+            assert!(root.im > 0f64);
+        }
+    }
+
+    #[test]
+    fn test_find_roots_rpoly_f32() {
+        // (x - 1)(x + 2)(x^2 + 1)
+        let (real, complex) = find_roots_rpoly(&[1f32, 1f32, -1f32, 1f32, -2f32]).unwrap();
+        assert_float_array_eq!(1e-6, real, [-2f32, 1f32]);
+        assert_eq!(complex.len(), 1);
+        assert_float_eq!(1e-6, complex[0].re, 0f32);
+        assert_float_eq!(1e-6, complex[0].im, 1f32);
+    }
+}
@@ -0,0 +1,279 @@
+// Copyright (c) 2015, Mikhail Vorotilov
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without
+// modification, are permitted provided that the following conditions are met:
+//
+// * Redistributions of source code must retain the above copyright notice, this
+//   list of conditions and the following disclaimer.
+//
+// * Redistributions in binary form must reproduce the above copyright notice,
+//   this list of conditions and the following disclaimer in the documentation
+//   and/or other materials provided with the distribution.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+// AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+// IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+// FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+// DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+// CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+// OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+// OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use super::super::Convergency;
+use super::super::FloatType;
+use super::super::Polynomial;
+use super::super::SearchError;
+use super::RootsVec;
+
+/// Sturm sequence of a polynomial: p, p', and negated remainders of the Euclidean algorithm.
+///
+/// The number of sign changes in the sequence at x decreases by one
+/// at each distinct real root of p, which allows to count and isolate roots.
+///
+/// # Examples
+///
+/// ```
+/// use roots::SturmChain;
+///
+/// // (x - 1)^2 * (x + 2) = x^3 - 3x + 2
+/// let chain = SturmChain::new(&[1f64, 0f64, -3f64, 2f64]);
+/// assert_eq!(chain.count_roots(-10f64, 10f64), 2);
+/// assert_eq!(chain.count_roots(0f64, 10f64), 1);
+/// ```
+#[derive(Debug, Clone)]
+pub struct SturmChain<F: FloatType> {
+    polynomials: Vec<Polynomial<F>>,
+}
+
+/// Scales a polynomial by a power of 2 to the maximal coefficient about 1; values are scaled exactly
+fn normalize<F: FloatType>(p: &Polynomial<F>) -> Polynomial<F> {
+    let mut coefficients = p.coefficients().to_vec();
+    super::vca::normalize(&mut coefficients);
+    Polynomial::new(coefficients)
+}
+
+impl<F: FloatType> SturmChain<F> {
+    /// Builds the Sturm sequence of a polynomial given by coefficients starting from the highest degree
+    pub fn new(coefficients: &[F]) -> Self {
+        let p = normalize(&Polynomial::from(coefficients));
+        let mut polynomials = vec![p.clone()];
+        if p.degree() > 0 {
+            let mut previous = p;
+            let mut current = normalize(&previous.derivative());
+            // Remainders below this level are rounding errors of the division
+            let threshold = F::epsilon() * F::from(previous.coefficients().len() as i16);
+            while current.degree() > 0 {
+                let (_, remainder) = previous.div_rem(&current);
+                let max = remainder.coefficients().iter().fold(F::zero(), |acc, &a| if a.abs() > acc { a.abs() } else { acc });
+                polynomials.push(current.clone());
+                if max <= threshold {
+                    // p has multiple roots; current is their greatest common divisor
+                    current = Polynomial::zero();
+                    break;
+                }
+                previous = current;
+                current = normalize(&-&remainder);
+            }
+            if current != Polynomial::zero() {
+                polynomials.push(current);
+            }
+        }
+        Self { polynomials }
+    }
+
+    /// Polynomials of the sequence
+    pub fn polynomials(&self) -> &[Polynomial<F>] {
+        &self.polynomials
+    }
+
+    /// Number of sign changes in the sequence at x, zero values are skipped
+    pub fn sign_changes(&self, x: F) -> usize {
+        let mut changes = 0;
+        let mut last_positive = None;
+        for p in self.polynomials.iter() {
+            let y = p.eval(x);
+            if y != F::zero() {
+                let positive = y > F::zero();
+                if last_positive == Some(!positive) {
+                    changes += 1;
+                }
+                last_positive = Some(positive);
+            }
+        }
+        changes
+    }
+
+    /// Number of distinct real roots in the interval (a, b]
+    pub fn count_roots(&self, a: F, b: F) -> usize {
+        self.sign_changes(a).saturating_sub(self.sign_changes(b))
+    }
+
+    /// Splits the interval (a, b] into intervals (a_i, b_i] containing exactly one distinct root each.
+    ///
+    /// Roots closer than the floating point resolution are reported in one interval.
+    pub fn isolate_roots(&self, a: F, b: F) -> Vec<(F, F)> {
+        let mut intervals = Vec::new();
+        let mut stack = vec![(a, b, self.sign_changes(a), self.sign_changes(b))];
+        while let Some((a, b, changes_a, changes_b)) = stack.pop() {
+            let count = changes_a.saturating_sub(changes_b);
+            if count == 0 {
+                continue;
+            }
+            let middle = (a + b) / F::two();
+            if count == 1 || middle == a || middle == b {
+                intervals.push((a, b));
+            } else {
+                let changes_middle = self.sign_changes(middle);
+                // The right half goes first, so intervals are popped in the increasing order
+                stack.push((middle, b, changes_middle, changes_b));
+                stack.push((a, middle, changes_a, changes_middle));
+            }
+        }
+        intervals
+    }
+
+    /// Refines the only distinct root in (a, b] found by isolate_roots
+    pub fn refine_root<C: Convergency<F>>(&self, a: F, b: F, convergency: &mut C) -> Result<F, SearchError> {
+        let p = &self.polynomials[0];
+        let pb = p.eval(b);
+        if pb == F::zero() {
+            return Ok(b);
+        }
+        let (mut a, mut pa) = (a, p.eval(a));
+        if pa == F::zero() {
+            // a is another root, outside of (a, b]: move a closer to the root in (a, b]
+            let mut right = b;
+            loop {
+                let middle = (a + right) / F::two();
+                if middle == a || middle == right {
+                    return Ok(middle);
+                }
+                let p_middle = p.eval(middle);
+                if p_middle == F::zero() {
+                    return Ok(middle);
+                }
+                if self.count_roots(middle, b) == 0 {
+                    right = middle;
+                } else {
+                    a = middle;
+                    pa = p_middle;
+                    break;
+                }
+            }
+        }
+        if (pa > F::zero()) != (pb > F::zero()) {
+            // A root of odd multiplicity: p changes its sign
+            super::super::find_root_regula_falsi(a, b, |x| p.eval(x), convergency)
+        } else {
+            // A root of even multiplicity: bisect by counting roots
+            let mut b = b;
+            let changes_b = self.sign_changes(b);
+            let mut iter = 0;
+            loop {
+                let middle = (a + b) / F::two();
+                if p.eval(middle) == F::zero() || middle == a || middle == b {
+                    return Ok(middle);
+                }
+                if self.sign_changes(middle) > changes_b {
+                    a = middle;
+                } else {
+                    b = middle;
+                }
+                if convergency.is_converged(b - a) {
+                    return Ok((a + b) / F::two());
+                }
+                iter += 1;
+                if convergency.is_iteration_limit_reached(iter) {
+                    return Err(SearchError::NoConvergency);
+                }
+            }
+        }
+    }
+}
+
+/// Finds all distinct real roots of a polynomial of any degree using the Sturm sequence.
+///
+/// Coefficients start from the highest degree. Roots are isolated by bisection
+/// inside the Cauchy bound 1 + max(|a_i / a_n|), then refined to the given convergency.
+/// Multiple roots are returned once. Returned roots are ordered.
+///
+/// # Failures
+/// ## NoConvergency
+/// A root cannot be refined within the given number of iterations.
+///
+/// # Examples
+///
+/// ```
+/// use roots::find_roots_sturm;
+/// use roots::SimpleConvergency;
+///
+/// // (x - 1)^2 * (x + 2) * (x^2 + 1) = 0
+/// let mut convergency = SimpleConvergency { eps: 1e-14f64, max_iter: 100 };
+/// let roots = find_roots_sturm(&[1f64, 0f64, -2f64, 2f64, -3f64, 2f64], &mut convergency).unwrap();
+/// // Returns [-2, 1]
+/// assert_eq!(roots.as_slice().len(), 2);
+/// ```
+pub fn find_roots_sturm<F, C>(coefficients: &[F], convergency: &mut C) -> Result<RootsVec<F>, SearchError>
+where
+    F: FloatType,
+    C: Convergency<F>,
+{
+    let a = super::trim(coefficients);
+    let mut roots = RootsVec::new();
+    if a.len() < 2 {
+        return Ok(roots);
+    }
+
+    let bound = super::bounds::cauchy_bound(a);
+    let chain = SturmChain::new(a);
+    for (left, right) in chain.isolate_roots(-bound, bound) {
+        roots.add_new_root(chain.refine_root(left, right, convergency)?);
+    }
+    Ok(roots)
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::super::*;
+
+    #[test]
+    fn test_sturm_chain() {
+        // x^3 - x has 3 roots
+        let chain = SturmChain::new(&[1f64, 0f64, -1f64, 0f64]);
+        assert_eq!(chain.polynomials().len(), 4);
+        assert_eq!(chain.count_roots(-2f64, 2f64), 3);
+        assert_eq!(chain.count_roots(-0.5f64, 0.5f64), 1);
+        assert_eq!(chain.isolate_roots(-2f64, 2f64).len(), 3);
+
+        // (x - 1)^3 has one distinct root
+        let chain = SturmChain::new(&[1f64, -3f64, 3f64, -1f64]);
+        assert_eq!(chain.count_roots(-2f64, 2f64), 1);
+        assert_eq!(SturmChain::new(&[1f64, 0f64, 1f64]).count_roots(-2f64, 2f64), 0);
+    }
+
+    #[test]
+    fn test_find_roots_sturm() {
+        let mut convergency = SimpleConvergency { eps: 1e-15f64, max_iter: 100 };
+        assert_eq!(find_roots_sturm(&[1f64, 0f64, 1f64], &mut convergency).unwrap().next(), None);
+        assert_float_array_eq!(1e-14, find_roots_sturm(&[1f64, -10f64, 35f64, -50f64, 24f64], &mut convergency).unwrap(), [1f64, 2f64, 3f64, 4f64]);
+        // Double root at 1 has no sign change
+        assert_float_array_eq!(1e-7, find_roots_sturm(&[1f64, 0f64, -2f64, 2f64, -3f64, 2f64], &mut convergency).unwrap(), [-2f64, 1f64]);
+
+        // Roots at the ends of isolating intervals
+        assert_eq!(find_roots_sturm(&[1f64, -1f64, 0f64], &mut convergency).unwrap().as_slice(), &[0f64, 1f64]);
+        assert_eq!(find_roots_sturm(&[1f64, 0f64, -1f64, 0f64], &mut convergency).unwrap().as_slice(), &[-1f64, 0f64, 1f64]);
+        assert_eq!(find_roots_sturm(&[1f64, -3f64, 2f64], &mut convergency).unwrap().as_slice(), &[1f64, 2f64]);
+        assert_eq!(find_roots_sturm(&[3f64, -3f64, 0f64], &mut convergency).unwrap().as_slice(), &[0f64, 1f64]);
+
+        // (x-1)(x-2)...(x-12)
+        let mut p = Polynomial::new(vec![1f64]);
+        for i in 1..=12 {
+            p = p * Polynomial::new(vec![1f64, -(i as f64)]);
+        }
+        let expected: Vec<f64> = (1..=12).map(|i| i as f64).collect();
+        assert_float_array_eq!(1e-6, find_roots_sturm(p.coefficients(), &mut convergency).unwrap(), expected);
+    }
+}
@@ -0,0 +1,440 @@
+// Copyright (c) 2015, Mikhail Vorotilov
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without
+// modification, are permitted provided that the following conditions are met:
+//
+// * Redistributions of source code must retain the above copyright notice, this
+//   list of conditions and the following disclaimer.
+//
+// * Redistributions in binary form must reproduce the above copyright notice,
+//   this list of conditions and the following disclaimer in the documentation
+//   and/or other materials provided with the distribution.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+// AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+// IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+// FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+// DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+// CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+// OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+// OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use super::super::Convergency;
+use super::super::FloatType;
+use super::super::SearchError;
+use super::super::Step;
+use super::RootsVec;
+
+/// Number of sign changes in a sequence of coefficients, zeros are skipped.
+///
+/// By the Descartes' rule of signs, the number of positive roots of a polynomial
+/// is less than the number of sign changes of its coefficients by an even number.
+pub fn sign_variations<F: FloatType>(coefficients: &[F]) -> usize {
+    let mut variations = 0;
+    let mut last_positive = None;
+    for &a in coefficients.iter().filter(|&&a| a != F::zero()) {
+        let positive = a > F::zero();
+        if last_positive == Some(!positive) {
+            variations += 1;
+        }
+        last_positive = Some(positive);
+    }
+    variations
+}
+
+/// Budan-Fourier bound on the number of real roots in (a, b], counted with multiplicities.
+///
+/// It is the difference of sign variations in the sequence p, p', p'', ... at a and at b.
+/// The actual number of roots is less than the bound by an even number.
+/// Neither a nor b shall be a root.
+///
+/// # Examples
+///
+/// ```
+/// use roots::budan_fourier_bound;
+///
+/// // x^3 - x has roots -1, 0 and 1
+/// assert_eq!(budan_fourier_bound(&[1f64, 0f64, -1f64, 0f64], 0.5f64, 2f64), 1);
+/// assert_eq!(budan_fourier_bound(&[1f64, 0f64, -1f64, 0f64], -2f64, 2f64), 3);
+/// ```
+pub fn budan_fourier_bound<F: FloatType>(coefficients: &[F], a: F, b: F) -> usize {
+    let mut derivatives = vec![F::zero(); coefficients.len()];
+    super::eval_derivatives(coefficients, a, &mut derivatives);
+    let variations_a = sign_variations(&derivatives);
+    super::eval_derivatives(coefficients, b, &mut derivatives);
+    let variations_b = sign_variations(&derivatives);
+    variations_a.saturating_sub(variations_b)
+}
+
+/// Replaces q(x) by q(x + 1); coefficients start from the lowest degree
+fn taylor_shift<F: FloatType>(c: &mut [F]) {
+    let n = c.len();
+    for i in 0..n {
+        for j in (i..n - 1).rev() {
+            c[j] = c[j] + c[j + 1];
+        }
+    }
+}
+
+/// Smallest power of 2 not less than x > 0
+fn power_of_two_above<F: FloatType>(x: F) -> F {
+    let mut power = F::one();
+    if x > F::one() {
+        while power < x {
+            power = power * F::two();
+        }
+    } else {
+        while power / F::two() >= x && power / F::two() > F::zero() {
+            power = power / F::two();
+        }
+    }
+    power
+}
+
+/// Scales coefficients by a power of 2 to the maximal magnitude about 1 to avoid overflows.
+///
+/// Signs and roots are kept, and no rounding errors are introduced.
+pub(super) fn normalize<F: FloatType>(c: &mut [F]) {
+    let max = c.iter().fold(F::zero(), |acc, &a| if a.abs() > acc { a.abs() } else { acc });
+    if max != F::zero() {
+        let scale = power_of_two_above(max);
+        for a in c.iter_mut() {
+            *a = *a / scale;
+        }
+    }
+}
+
+/// Descartes' bound on the number of roots of q in (0, 1):
+/// sign variations of (x + 1)^n * q(1 / (x + 1)).
+///
+/// Its lowest coefficient is q(1); it is set to zero when 1 is known to be a root,
+/// so rounding errors do not count it. A root at 0 is divided out of q when it is found.
+fn descartes_bound<F: FloatType>(c: &[F], root_at_1: bool) -> usize {
+    let mut reversed: Vec<F> = c.iter().rev().cloned().collect();
+    taylor_shift(&mut reversed);
+    if root_at_1 {
+        reversed[0] = F::zero();
+    }
+    sign_variations(&reversed)
+}
+
+/// Isolates positive roots of p in (0, bound). Coefficients start from the highest degree, p(0) is not zero.
+fn isolate_positive_roots<F: FloatType>(p: &[F], bound: F, intervals: &mut Vec<(F, F)>) {
+    // q(x) = p(bound * x) has the same roots in (0, 1); its coefficients start from the lowest degree
+    let mut scale = F::one();
+    let mut q: Vec<F> = p
+        .iter()
+        .rev()
+        .map(|&a| {
+            let scaled = a * scale;
+            scale = scale * bound;
+            scaled
+        })
+        .collect();
+    normalize(&mut q);
+
+    // Roots of p in (left, left + width) are left + width * (roots of q in (0, 1))
+    let mut stack = vec![(q, F::zero(), bound)];
+    while let Some((q, left, width)) = stack.pop() {
+        let right = left + width;
+        let middle = left + width / F::two();
+
+        // Values and the Budan-Fourier bound are calculated from the original coefficients,
+        // so they do not accumulate rounding errors of the transformations. The Budan-Fourier bound
+        // needs ends which are not roots; a root at the left end has been divided out of q
+        let (root_at_left, root_at_right) = (super::eval(p, left) == F::zero(), super::eval(p, right) == F::zero());
+        let count = if root_at_left || root_at_right {
+            descartes_bound(&q, root_at_right)
+        } else {
+            budan_fourier_bound(p, left, right).min(descartes_bound(&q, false))
+        };
+        if count == 0 {
+            continue;
+        } else if count == 1 || middle == left || middle == right {
+            // One root, or a cluster narrower than the floating point resolution
+            intervals.push((left, right));
+            continue;
+        }
+
+        // Left half: q(x / 2); right half: q((x + 1) / 2)
+        let mut scale = F::one();
+        let mut q_left: Vec<F> = q
+            .iter()
+            .map(|&a| {
+                let scaled = a * scale;
+                scale = scale / F::two();
+                scaled
+            })
+            .collect();
+        normalize(&mut q_left);
+        let mut q_right = q_left.clone();
+        taylor_shift(&mut q_right);
+
+        let half = width / F::two();
+        let mut derivatives = vec![F::zero(); p.len()];
+        super::eval_derivatives(p, middle, &mut derivatives);
+        let multiplicity = derivatives.iter().take_while(|&&d| d == F::zero()).count();
+        if multiplicity > 0 {
+            // The middle point is a root; divide it out with its multiplicity
+            intervals.push((middle, middle));
+            q_right.drain(..multiplicity);
+        }
+        stack.push((q_right, middle, half));
+        stack.push((q_left, left, half));
+    }
+}
+
+/// Isolates real roots of a polynomial of any degree by the Vincent-Collins-Akritas bisection.
+///
+/// Coefficients start from the highest degree. Returns ordered disjoint intervals;
+/// each one contains exactly one root, or is a single point (a, a) for a root found exactly.
+/// Intervals are found inside twice the Fujiwara bound by the Descartes' rule of signs; the
+/// Budan-Fourier bound from the original coefficients limits the effect of rounding errors.
+/// A multiple root, or a cluster of roots closer than the floating point resolution,
+/// is reported as one interval.
+///
+/// # Examples
+///
+/// ```
+/// use roots::isolate_roots_vca;
+///
+/// // x^3 - x has roots -1, 0 and 1
+/// let intervals = isolate_roots_vca(&[1f64, 0f64, -1f64, 0f64]);
+/// assert_eq!(intervals.len(), 3);
+/// assert_eq!(intervals[1], (0f64, 0f64));
+/// ```
+pub fn isolate_roots_vca<F: FloatType>(coefficients: &[F]) -> Vec<(F, F)> {
+    let a = super::trim(coefficients);
+    let mut intervals = Vec::new();
+    if a.len() < 2 {
+        return intervals;
+    }
+
+    // Zero roots are divided out and reported as a point
+    let zero_roots = a.iter().rev().take_while(|&&x| x == F::zero()).count();
+    let a = &a[..a.len() - zero_roots];
+    if zero_roots > 0 {
+        intervals.push((F::zero(), F::zero()));
+    }
+
+    if a.len() > 1 {
+        // Bisection by powers of 2 keeps the transformations of coefficients exact.
+        // Roots may lie at the Fujiwara bound itself (it is exact for degree 1), so it is doubled
+        let bound = F::two() * power_of_two_above(super::bounds::fujiwara_bound(a));
+
+        // Negative roots of p are positive roots of p(-x)
+        let n = a.len() - 1;
+        let p_minus_x: Vec<F> = a.iter().enumerate().map(|(i, &c)| if (n - i) % 2 == 1 { -c } else { c }).collect();
+        let mut negative = Vec::new();
+        isolate_positive_roots(&p_minus_x, bound, &mut negative);
+        intervals.extend(negative.iter().map(|&(left, right)| (-right, -left)));
+
+        isolate_positive_roots(a, bound, &mut intervals);
+    }
+    intervals.sort_by(|x, y| x.partial_cmp(y).unwrap_or(core::cmp::Ordering::Equal));
+    intervals
+}
+
+/// Finds real roots of a polynomial of any degree isolated by the Vincent-Collins-Akritas bisection.
+///
+/// Coefficients start from the highest degree. Isolating intervals are narrowed by bisection
+/// to a relative width of the square root of epsilon, which does not count against the convergency.
+/// Roots are then refined by the Brent-Dekker method to the given convergency; values within
+/// rounding errors of the evaluation are accepted too, so a few iterations suffice even for tolerances
+/// below the floating point resolution.
+/// A multiple root is returned once. Returned roots are ordered.
+///
+/// # Failures
+/// ## NoConvergency
+/// A root cannot be refined within the given number of iterations.
+///
+/// # Examples
+///
+/// ```
+/// use roots::find_roots_vca;
+///
+/// // x^5 - 5x^3 + 4x = x(x^2 - 1)(x^2 - 4)
+/// let roots = find_roots_vca(&[1f64, 0f64, -5f64, 0f64, 4f64, 0f64], &mut 1e-15f64).unwrap();
+/// // Returns [-2, -1, 0, 1, 2]
+/// assert_eq!(roots.as_slice().len(), 5);
+/// ```
+pub fn find_roots_vca<F, C>(coefficients: &[F], convergency: &mut C) -> Result<RootsVec<F>, SearchError>
+where
+    F: FloatType,
+    C: Convergency<F>,
+{
+    let a = super::trim(coefficients);
+    let mut roots = RootsVec::new();
+    for (left, right) in isolate_roots_vca(a) {
+        let root = if left == right {
+            left
+        } else {
+            refine_root(a, left, right, convergency)?
+        };
+        roots.add_new_root(root);
+    }
+    Ok(roots)
+}
+
+/// Sign of p next to x, on the right side of x or on the left one.
+///
+/// If x is a root, the sign is given by the first non-zero derivative.
+fn is_positive_near<F: FloatType>(p: &[F], x: F, on_right: bool) -> bool {
+    let mut derivatives = vec![F::zero(); p.len()];
+    super::eval_derivatives(p, x, &mut derivatives);
+    match derivatives.iter().enumerate().find(|&(_, &d)| d != F::zero()) {
+        Some((k, &d)) => (d > F::zero()) == (on_right || k % 2 == 0),
+        None => true,
+    }
+}
+
+/// Refines the only root in the open interval (left, right).
+///
+/// The ends may be exact roots found by the isolation.
+fn refine_root<F, C>(p: &[F], left: F, right: F, convergency: &mut C) -> Result<F, SearchError>
+where
+    F: FloatType,
+    C: Convergency<F>,
+{
+    let positive_left = is_positive_near(p, left, true);
+    if positive_left == is_positive_near(p, right, false) {
+        // A root of even multiplicity does not change the sign; the interval is as narrow as possible
+        return Ok((left + right) / F::two());
+    }
+
+    // The ends are moved inside if they are roots, and the interval is narrowed by bisection
+    // to a relative width of the square root of epsilon, so the iterations of the convergency
+    // are left for the fast final convergence of the Brent-Dekker method
+    let tolerance = F::epsilon().sqrt();
+    let (mut left, mut right) = (left, right);
+    while super::eval(p, left) == F::zero()
+        || super::eval(p, right) == F::zero()
+        || right - left > tolerance * (left.abs() + right.abs())
+    {
+        let middle = (left + right) / F::two();
+        let y = super::eval(p, middle);
+        if y == F::zero() || middle == left || middle == right {
+            return Ok(middle);
+        }
+        if (y > F::zero()) == positive_left {
+            left = middle;
+        } else {
+            right = middle;
+        }
+    }
+    let r = if left.abs() > right.abs() { left.abs() } else { right.abs() };
+    let mut refinement = Refinement {
+        convergency,
+        rounding_error: F::epsilon() * p.iter().fold(F::zero(), |acc, &a| acc * r + a.abs()),
+    };
+    super::super::find_root_brent(left, right, |x| super::eval(p, x), &mut refinement)
+}
+
+/// Convergency of the refinement, which also accepts values within rounding errors of the evaluation
+struct Refinement<'a, F: FloatType, C> {
+    convergency: &'a mut C,
+    /// Typical rounding error of the Horner scheme inside the isolating interval; the worst case
+    /// bound of polynomial::rounding_error is 4n times larger and would stop the refinement too early
+    rounding_error: F,
+}
+
+impl<'a, F: FloatType, C: Convergency<F>> Convergency<F> for Refinement<'a, F, C> {
+    fn is_root_found(&mut self, y: F) -> bool {
+        y.abs() <= self.rounding_error || self.convergency.is_root_found(y)
+    }
+    fn is_converged(&mut self, dx: F) -> bool {
+        self.convergency.is_converged(dx)
+    }
+    fn is_iteration_limit_reached(&mut self, iter: usize) -> bool {
+        self.convergency.is_iteration_limit_reached(iter)
+    }
+    fn on_iteration(&mut self, step: &Step<F>) {
+        self.convergency.on_iteration(step)
+    }
+    fn on_result(&mut self, root: Option<F>) {
+        self.convergency.on_result(root)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::super::*;
+
+    #[test]
+    fn test_sign_variations() {
+        assert_eq!(sign_variations(&[1f64, 0f64, -1f64, -2f64, 3f64]), 2);
+        assert_eq!(sign_variations::<f32>(&[]), 0);
+        assert_eq!(budan_fourier_bound(&[1f64, 0f64, 1f64], -1f64, 1f64), 2);
+    }
+
+    #[test]
+    fn test_isolate_roots_vca() {
+        assert!(isolate_roots_vca(&[1f64, 0f64, 1f64]).is_empty());
+        assert_eq!(isolate_roots_vca(&[1f64, 0f64, 0f64]), vec![(0f64, 0f64)]);
+        let intervals = isolate_roots_vca(&[1f64, -10f64, 35f64, -50f64, 24f64]);
+        assert_eq!(intervals.len(), 4);
+        for (i, &(left, right)) in intervals.iter().enumerate() {
+            let root = (i + 1) as f64;
+            assert!(left <= root && root <= right);
+        }
+        // Double root at 1 and a simple root at -2
+        assert_eq!(isolate_roots_vca(&[1f64, 0f64, -3f64, 2f64]).len(), 2);
+    }
+
+    #[test]
+    fn test_find_roots_vca() {
+        assert_float_array_eq!(1e-15, find_roots_vca(&[1f64, 0f64, -5f64, 0f64, 4f64, 0f64], &mut 1e-15f64).unwrap(), [-2f64, -1f64, 0f64, 1f64, 2f64]);
+        assert_float_array_eq!(1e-6, find_roots_vca(&[2f32, -3f32, -11f32, 6f32], &mut 1e-6f32).unwrap(), [-2f32, 0.5f32, 3f32]);
+
+        // Roots at the Fujiwara bound
+        assert_eq!(find_roots_vca(&[1f64, -1f64], &mut 1e-15f64).unwrap().as_slice(), &[1f64]);
+        assert_eq!(find_roots_vca(&[1f64, 1f64], &mut 1e-15f64).unwrap().as_slice(), &[-1f64]);
+        assert_eq!(find_roots_vca(&[1f64, -2f64], &mut 1e-15f64).unwrap().as_slice(), &[2f64]);
+        assert_eq!(find_roots_vca(&[1f64, -0.5f64], &mut 1e-15f64).unwrap().as_slice(), &[0.5f64]);
+        assert_eq!(find_roots_vca(&[1f64, -4f64], &mut 1e-15f64).unwrap().as_slice(), &[4f64]);
+        assert_eq!(find_roots_vca(&[1f64, -1f64, 0f64], &mut 1e-15f64).unwrap().as_slice(), &[0f64, 1f64]);
+        assert_eq!(find_roots_vca(&[1f64, -3f64, 2f64], &mut 1e-15f64).unwrap().as_slice(), &[1f64, 2f64]);
+        assert_eq!(find_roots_vca(&[1f64, 0f64, -16f64], &mut 1e-15f64).unwrap().as_slice(), &[-4f64, 4f64]);
+
+        // A root at a bisection midpoint is divided out, and its neighbour is kept
+        for &neighbour in &[1.1f64, 1.001f64, 0.9f64] {
+            let p = coefficients_from_roots(&[(Complex::from(1f64), 1), (Complex::from(neighbour), 1)]);
+            assert_float_array_eq!(1e-12, find_roots_vca(&p, &mut 1e-15f64).unwrap(), [1f64.min(neighbour), 1f64.max(neighbour)]);
+        }
+
+        // Isolating intervals are narrowed by bisection, so the default convergency is enough for the refinement
+        let p = coefficients_from_roots(&[(Complex::from(1f64), 1), (Complex::from(1.1f64), 1), (Complex::from(-5f64), 1)]);
+        assert_float_array_eq!(1e-14, find_roots_vca(&p, &mut 1e-15f64).unwrap(), [-5f64, 1f64, 1.1f64]);
+
+        // Values near the root are rounding errors above 1e-15; 30 iterations of the default convergency are enough
+        let p = [
+            2.5968168551191884f64,
+            -6.431642399299782f64,
+            5.222597362753079f64,
+            2.9014515216614356f64,
+            -6.308414543260222f64,
+            7.703974302798125f64,
+        ];
+        assert_float_array_eq!(1e-15, find_roots_vca(&p, &mut 1e-15f64).unwrap(), [-1.0568370043425688f64]);
+
+        // Degree 25 with roots -12..12
+        let mut p = Polynomial::new(vec![1f64]);
+        for i in -12..=12 {
+            p = p * Polynomial::new(vec![1f64, -(i as f64)]);
+        }
+        let expected: Vec<f64> = (-12..=12).map(|i| i as f64).collect();
+        assert_float_array_eq!(1e-6, find_roots_vca(p.coefficients(), &mut 1e-15f64).unwrap(), expected);
+
+        // Degree 25 with roots -11.5..12.5, none of them is found exactly by bisection
+        let mut p = Polynomial::new(vec![1f64]);
+        for i in -12..=12 {
+            p = p * Polynomial::new(vec![1f64, -(i as f64 + 0.5f64)]);
+        }
+        let expected: Vec<f64> = (-12..=12).map(|i| i as f64 + 0.5f64).collect();
+        let mut convergency = SimpleConvergency { eps: 1e-9f64, max_iter: 100 };
+        assert_float_array_eq!(1e-8, find_roots_vca(p.coefficients(), &mut convergency).unwrap(), expected);
+    }
+}